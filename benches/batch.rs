@@ -0,0 +1,129 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Compares rebuilding and updating a 2,000 items menu attached to two windows
+//! with and without [`Menu::batch`].
+//!
+//! On Linux, "rebuild (batch)" destroys the widgets of the removed items and creates
+//! the widgets of the new items for both windows at the end of the batch, see [`Menu::batch`].
+//!
+//! Run with `cargo bench --bench batch`, it prints the average time of each case
+//! and the speedup of the batched one. It needs a display, and GTK 3 on Linux.
+
+use std::time::{Duration, Instant};
+
+use muda::{
+    accelerator::{Accelerator, Code, Modifiers},
+    Menu, MenuItem, Submenu,
+};
+#[cfg(target_os = "linux")]
+use tao::platform::unix::WindowExtUnix;
+#[cfg(target_os = "windows")]
+use tao::platform::windows::WindowExtWindows;
+use tao::{event_loop::EventLoopBuilder, window::WindowBuilder};
+
+const ITEMS: usize = 2_000;
+const ROUNDS: u32 = 5;
+
+const KEYS: [Code; 12] = [
+    Code::F1,
+    Code::F2,
+    Code::F3,
+    Code::F4,
+    Code::F5,
+    Code::F6,
+    Code::F7,
+    Code::F8,
+    Code::F9,
+    Code::F10,
+    Code::F11,
+    Code::F12,
+];
+
+fn rebuild(submenu: &Submenu) -> Vec<MenuItem> {
+    for item in submenu.items() {
+        submenu.remove(item.as_menuitem_unchecked()).unwrap();
+    }
+
+    let items = (0..ITEMS)
+        .map(|i| {
+            let accelerator = Accelerator::new(
+                Some(Modifiers::CONTROL | Modifiers::SHIFT),
+                KEYS[i % KEYS.len()],
+            );
+            MenuItem::new(format!("Item {i}"), true, Some(accelerator))
+        })
+        .collect::<Vec<_>>();
+    for item in &items {
+        submenu.append(item).unwrap();
+    }
+    items
+}
+
+fn update(items: &[MenuItem], round: u32) {
+    for (i, item) in items.iter().enumerate() {
+        item.set_text(format!("Item {i} ({round})"));
+        item.set_enabled(i % 2 == round as usize % 2);
+    }
+}
+
+fn measure(name: &str, mut f: impl FnMut(u32)) -> Duration {
+    let start = Instant::now();
+    for round in 0..ROUNDS {
+        f(round);
+    }
+    let elapsed = start.elapsed() / ROUNDS;
+    println!("{name:<24} {elapsed:>12.2?}");
+    elapsed
+}
+
+fn main() {
+    let event_loop = EventLoopBuilder::new().build();
+    let window = WindowBuilder::new().build(&event_loop).unwrap();
+    let window2 = WindowBuilder::new().build(&event_loop).unwrap();
+
+    let menu = Menu::new();
+    let submenu = Submenu::new("Items", true);
+    menu.append(&submenu).unwrap();
+
+    #[cfg(target_os = "windows")]
+    unsafe {
+        menu.init_for_hwnd(window.hwnd() as _).unwrap();
+        menu.init_for_hwnd(window2.hwnd() as _).unwrap();
+    }
+    #[cfg(target_os = "linux")]
+    {
+        menu.init_for_gtk_window(window.gtk_window(), window.default_vbox())
+            .unwrap();
+        menu.init_for_gtk_window(window2.gtk_window(), window2.default_vbox())
+            .unwrap();
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let _ = (&window, &window2);
+        menu.init_for_nsapp();
+    }
+
+    println!("{ITEMS} items, average of {ROUNDS} rounds");
+    let plain = measure("rebuild", |_| {
+        rebuild(&submenu);
+    });
+    let batched = measure("rebuild (batch)", |_| {
+        menu.batch(|_| rebuild(&submenu));
+    });
+    println!(
+        "speedup: {:.1}x",
+        plain.as_secs_f64() / batched.as_secs_f64()
+    );
+
+    let items = rebuild(&submenu);
+    let plain = measure("update", |round| update(&items, round));
+    let batched = measure("update (batch)", |round| {
+        menu.batch(|_| update(&items, round))
+    });
+    println!(
+        "speedup: {:.1}x",
+        plain.as_secs_f64() / batched.as_secs_f64()
+    );
+}
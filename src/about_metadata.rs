@@ -72,6 +72,35 @@ impl AboutMetadata {
                 .unwrap_or_default()
         ))
     }
+
+    /// Returns `true` if `other` describes the same about dialog.
+    pub(crate) fn is_same(&self, other: &AboutMetadata) -> bool {
+        let AboutMetadata {
+            name,
+            version,
+            short_version,
+            authors,
+            comments,
+            copyright,
+            license,
+            website,
+            website_label,
+            credits,
+            icon,
+        } = self;
+
+        *name == other.name
+            && *version == other.version
+            && *short_version == other.short_version
+            && *authors == other.authors
+            && *comments == other.comments
+            && *copyright == other.copyright
+            && *license == other.license
+            && *website == other.website
+            && *website_label == other.website_label
+            && *credits == other.credits
+            && icon.as_ref().map(|i| &i.inner) == other.icon.as_ref().map(|i| &i.inner)
+    }
 }
 
 /// Creates [`AboutMetadata`] from [Cargo metadata][cargo]. The following fields are set by this function.
@@ -116,6 +116,117 @@ impl Accelerator {
         let key = key.borrow();
         self.mods == *modifiers & base_mods && self.key == *key
     }
+
+    /// Returns this accelerator as it is shown to the user on the current platform,
+    /// like `Ctrl+Shift+P`, or `⇧⌘P` on macOS, see [`Accelerator::label_for`].
+    pub fn label(&self) -> String {
+        self.label_for(Platform::current())
+    }
+
+    /// Returns this accelerator as it is shown to the user on `platform`,
+    /// like `Ctrl+Shift+P` on Windows and Linux, or `⇧⌘P` on macOS.
+    ///
+    /// `CmdOrCtrl` is resolved when the accelerator is parsed, so an accelerator parsed from it
+    /// uses `Ctrl` on Windows and Linux and `Cmd` on macOS, whatever the `platform` is.
+    pub fn label_for(&self, platform: Platform) -> String {
+        let modifiers: &[(Modifiers, &str)] = match platform {
+            Platform::MacOS => &[
+                (Modifiers::CONTROL, "⌃"),
+                (Modifiers::ALT, "⌥"),
+                (Modifiers::SHIFT, "⇧"),
+                (Modifiers::SUPER, "⌘"),
+            ],
+            Platform::Windows | Platform::Linux => &[
+                (Modifiers::CONTROL, "Ctrl+"),
+                (Modifiers::SHIFT, "Shift+"),
+                (Modifiers::ALT, "Alt+"),
+                (Modifiers::SUPER, "Super+"),
+            ],
+        };
+
+        let mut label = String::new();
+        for (modifier, name) in modifiers {
+            if self.mods.contains(*modifier) {
+                label.push_str(name);
+            }
+        }
+
+        label.push_str(&key_label(self.key, platform));
+        label
+    }
+}
+
+/// A platform to format accelerator labels for, see [`Accelerator::label_for`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// Modifiers and keys are spelled out, like `Ctrl+Shift+Del`.
+    Windows,
+    /// Modifiers and keys are spelled out, like `Ctrl+Shift+Del`.
+    Linux,
+    /// Modifiers and some keys are shown as symbols, like `⇧⌘⌦`.
+    MacOS,
+}
+
+impl Platform {
+    /// Returns the platform muda is compiled for.
+    ///
+    /// Platforms other than Windows and macOS are treated as Linux.
+    pub fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            Platform::Windows
+        } else if cfg!(target_os = "macos") {
+            Platform::MacOS
+        } else {
+            Platform::Linux
+        }
+    }
+}
+
+/// Returns the name of `key` as it is shown in accelerator labels on `platform`.
+fn key_label(key: Code, platform: Platform) -> String {
+    let name = match (key, platform) {
+        (Code::Comma, _) => ",",
+        (Code::Minus, _) => "-",
+        (Code::Period, _) => ".",
+        (Code::Equal, _) => "=",
+        (Code::Semicolon, _) => ";",
+        (Code::Slash, _) => "/",
+        (Code::Backslash, _) => "\\",
+        (Code::Quote, _) => "'",
+        (Code::Backquote, _) => "`",
+        (Code::BracketLeft, _) => "[",
+        (Code::BracketRight, _) => "]",
+        (Code::Escape, Platform::MacOS) => "⎋",
+        (Code::Enter, Platform::MacOS) => "↩",
+        (Code::Backspace, Platform::MacOS) => "⌫",
+        (Code::Delete, Platform::MacOS) => "⌦",
+        (Code::Tab, Platform::MacOS) => "⇥",
+        (Code::ArrowUp, Platform::MacOS) => "↑",
+        (Code::ArrowDown, Platform::MacOS) => "↓",
+        (Code::ArrowLeft, Platform::MacOS) => "←",
+        (Code::ArrowRight, Platform::MacOS) => "→",
+        (Code::Escape, _) => "Esc",
+        (Code::Delete, _) => "Del",
+        (Code::Insert, Platform::Windows | Platform::Linux) => "Ins",
+        (Code::ArrowUp, _) => "Up",
+        (Code::ArrowDown, _) => "Down",
+        (Code::ArrowLeft, _) => "Left",
+        (Code::ArrowRight, _) => "Right",
+        (Code::PageUp, _) => "PgUp",
+        (Code::PageDown, _) => "PgDn",
+        (key, _) => {
+            let name = key.to_string();
+            return match name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+            {
+                Some(name) => name.to_string(),
+                None => name.replace("Numpad", "Num "),
+            };
+        }
+    };
+    name.to_string()
 }
 
 impl FromStr for Accelerator {
@@ -226,6 +337,9 @@ fn parse_key(key: &str) -> Result<Code, AcceleratorParseError> {
         "DIGIT8" | "8" => Ok(Digit8),
         "DIGIT9" | "9" => Ok(Digit9),
         "EQUAL" | "=" => Ok(Equal),
+        "INTLBACKSLASH" => Ok(IntlBackslash),
+        "INTLRO" => Ok(IntlRo),
+        "INTLYEN" => Ok(IntlYen),
         "KEYA" | "A" => Ok(KeyA),
         "KEYB" | "B" => Ok(KeyB),
         "KEYC" | "C" => Ok(KeyC),
@@ -257,19 +371,35 @@ fn parse_key(key: &str) -> Result<Code, AcceleratorParseError> {
         "QUOTE" | "'" => Ok(Quote),
         "SEMICOLON" | ";" => Ok(Semicolon),
         "SLASH" | "/" => Ok(Slash),
+        "ALTLEFT" => Ok(AltLeft),
+        "ALTRIGHT" => Ok(AltRight),
         "BACKSPACE" => Ok(Backspace),
         "CAPSLOCK" => Ok(CapsLock),
-        "ENTER" => Ok(Enter),
+        "CONTEXTMENU" | "MENU" | "APPS" => Ok(ContextMenu),
+        "CONTROLLEFT" => Ok(ControlLeft),
+        "CONTROLRIGHT" => Ok(ControlRight),
+        "ENTER" | "RETURN" => Ok(Enter),
+        "METALEFT" => Ok(MetaLeft),
+        "METARIGHT" => Ok(MetaRight),
+        "SHIFTLEFT" => Ok(ShiftLeft),
+        "SHIFTRIGHT" => Ok(ShiftRight),
         "SPACE" => Ok(Space),
         "TAB" => Ok(Tab),
+        "CONVERT" => Ok(Convert),
+        "KANAMODE" => Ok(KanaMode),
+        "LANG1" => Ok(Lang1),
+        "LANG2" => Ok(Lang2),
+        "LANG3" => Ok(Lang3),
+        "LANG4" => Ok(Lang4),
+        "LANG5" => Ok(Lang5),
+        "NONCONVERT" => Ok(NonConvert),
         "DELETE" => Ok(Delete),
         "END" => Ok(End),
+        "HELP" => Ok(Help),
         "HOME" => Ok(Home),
         "INSERT" => Ok(Insert),
         "PAGEDOWN" => Ok(PageDown),
         "PAGEUP" => Ok(PageUp),
-        "PRINTSCREEN" => Ok(PrintScreen),
-        "SCROLLLOCK" => Ok(ScrollLock),
         "ARROWDOWN" | "DOWN" => Ok(ArrowDown),
         "ARROWLEFT" | "LEFT" => Ok(ArrowLeft),
         "ARROWRIGHT" | "RIGHT" => Ok(ArrowRight),
@@ -286,13 +416,70 @@ fn parse_key(key: &str) -> Result<Code, AcceleratorParseError> {
         "NUMPAD8" | "NUM8" => Ok(Numpad8),
         "NUMPAD9" | "NUM9" => Ok(Numpad9),
         "NUMPADADD" | "NUMADD" | "NUMPADPLUS" | "NUMPLUS" => Ok(NumpadAdd),
+        "NUMPADBACKSPACE" | "NUMBACKSPACE" => Ok(NumpadBackspace),
+        "NUMPADCLEAR" | "NUMCLEAR" => Ok(NumpadClear),
+        "NUMPADCLEARENTRY" | "NUMCLEARENTRY" => Ok(NumpadClearEntry),
+        "NUMPADCOMMA" | "NUMCOMMA" => Ok(NumpadComma),
         "NUMPADDECIMAL" | "NUMDECIMAL" => Ok(NumpadDecimal),
         "NUMPADDIVIDE" | "NUMDIVIDE" => Ok(NumpadDivide),
         "NUMPADENTER" | "NUMENTER" => Ok(NumpadEnter),
         "NUMPADEQUAL" | "NUMEQUAL" => Ok(NumpadEqual),
+        "NUMPADHASH" | "NUMHASH" => Ok(NumpadHash),
+        "NUMPADMEMORYADD" => Ok(NumpadMemoryAdd),
+        "NUMPADMEMORYCLEAR" => Ok(NumpadMemoryClear),
+        "NUMPADMEMORYRECALL" => Ok(NumpadMemoryRecall),
+        "NUMPADMEMORYSTORE" => Ok(NumpadMemoryStore),
+        "NUMPADMEMORYSUBTRACT" => Ok(NumpadMemorySubtract),
         "NUMPADMULTIPLY" | "NUMMULTIPLY" => Ok(NumpadMultiply),
+        "NUMPADPARENLEFT" | "NUMPARENLEFT" => Ok(NumpadParenLeft),
+        "NUMPADPARENRIGHT" | "NUMPARENRIGHT" => Ok(NumpadParenRight),
+        "NUMPADSTAR" | "NUMSTAR" => Ok(NumpadStar),
         "NUMPADSUBTRACT" | "NUMSUBTRACT" => Ok(NumpadSubtract),
         "ESCAPE" | "ESC" => Ok(Escape),
+        "FN" => Ok(Fn),
+        "FNLOCK" => Ok(FnLock),
+        "PRINTSCREEN" => Ok(PrintScreen),
+        "SCROLLLOCK" => Ok(ScrollLock),
+        "PAUSE" | "BREAK" => Ok(Pause),
+        "BROWSERBACK" => Ok(BrowserBack),
+        "BROWSERFAVORITES" => Ok(BrowserFavorites),
+        "BROWSERFORWARD" => Ok(BrowserForward),
+        "BROWSERHOME" => Ok(BrowserHome),
+        "BROWSERREFRESH" => Ok(BrowserRefresh),
+        "BROWSERSEARCH" => Ok(BrowserSearch),
+        "BROWSERSTOP" => Ok(BrowserStop),
+        "EJECT" => Ok(Eject),
+        "LAUNCHAPP1" => Ok(LaunchApp1),
+        "LAUNCHAPP2" => Ok(LaunchApp2),
+        "LAUNCHMAIL" => Ok(LaunchMail),
+        "MEDIAPLAYPAUSE" | "PLAYPAUSE" => Ok(MediaPlayPause),
+        "MEDIASELECT" => Ok(MediaSelect),
+        "MEDIASTOP" => Ok(MediaStop),
+        "MEDIATRACKNEXT" | "MEDIANEXTTRACK" => Ok(MediaTrackNext),
+        "MEDIATRACKPREVIOUS" | "MEDIAPREVIOUSTRACK" | "MEDIAPREVTRACK" => Ok(MediaTrackPrevious),
+        "POWER" => Ok(Power),
+        "SLEEP" => Ok(Sleep),
+        "AUDIOVOLUMEDOWN" | "VOLUMEDOWN" => Ok(AudioVolumeDown),
+        "AUDIOVOLUMEMUTE" | "VOLUMEMUTE" => Ok(AudioVolumeMute),
+        "AUDIOVOLUMEUP" | "VOLUMEUP" => Ok(AudioVolumeUp),
+        "WAKEUP" => Ok(WakeUp),
+        "HYPER" => Ok(Hyper),
+        "SUPER" => Ok(Super),
+        "TURBO" => Ok(Turbo),
+        "ABORT" => Ok(Abort),
+        "RESUME" => Ok(Resume),
+        "SUSPEND" => Ok(Suspend),
+        "AGAIN" => Ok(Again),
+        "COPY" => Ok(Copy),
+        "CUT" => Ok(Cut),
+        "FIND" => Ok(Find),
+        "OPEN" => Ok(Open),
+        "PASTE" => Ok(Paste),
+        "PROPS" => Ok(Props),
+        "SELECT" => Ok(Select),
+        "UNDO" => Ok(Undo),
+        "HIRAGANA" => Ok(Hiragana),
+        "KATAKANA" => Ok(Katakana),
         "F1" => Ok(F1),
         "F2" => Ok(F2),
         "F3" => Ok(F3),
@@ -305,9 +492,6 @@ fn parse_key(key: &str) -> Result<Code, AcceleratorParseError> {
         "F10" => Ok(F10),
         "F11" => Ok(F11),
         "F12" => Ok(F12),
-        "AUDIOVOLUMEDOWN" | "VOLUMEDOWN" => Ok(AudioVolumeDown),
-        "AUDIOVOLUMEUP" | "VOLUMEUP" => Ok(AudioVolumeUp),
-        "AUDIOVOLUMEMUTE" | "VOLUMEMUTE" => Ok(AudioVolumeMute),
         "F13" => Ok(F13),
         "F14" => Ok(F14),
         "F15" => Ok(F15),
@@ -320,6 +504,37 @@ fn parse_key(key: &str) -> Result<Code, AcceleratorParseError> {
         "F22" => Ok(F22),
         "F23" => Ok(F23),
         "F24" => Ok(F24),
+        "F25" => Ok(F25),
+        "F26" => Ok(F26),
+        "F27" => Ok(F27),
+        "F28" => Ok(F28),
+        "F29" => Ok(F29),
+        "F30" => Ok(F30),
+        "F31" => Ok(F31),
+        "F32" => Ok(F32),
+        "F33" => Ok(F33),
+        "F34" => Ok(F34),
+        "F35" => Ok(F35),
+        "BRIGHTNESSDOWN" => Ok(BrightnessDown),
+        "BRIGHTNESSUP" => Ok(BrightnessUp),
+        "DISPLAYTOGGLEINTEXT" => Ok(DisplayToggleIntExt),
+        "KEYBOARDLAYOUTSELECT" => Ok(KeyboardLayoutSelect),
+        "LAUNCHASSISTANT" => Ok(LaunchAssistant),
+        "LAUNCHCONTROLPANEL" => Ok(LaunchControlPanel),
+        "LAUNCHSCREENSAVER" => Ok(LaunchScreenSaver),
+        "MAILFORWARD" => Ok(MailForward),
+        "MAILREPLY" => Ok(MailReply),
+        "MAILSEND" => Ok(MailSend),
+        "MEDIAFASTFORWARD" => Ok(MediaFastForward),
+        "MEDIAPAUSE" => Ok(MediaPause),
+        "MEDIAPLAY" => Ok(MediaPlay),
+        "MEDIARECORD" => Ok(MediaRecord),
+        "MEDIAREWIND" => Ok(MediaRewind),
+        "MICROPHONEMUTETOGGLE" => Ok(MicrophoneMuteToggle),
+        "PRIVACYSCREENTOGGLE" => Ok(PrivacyScreenToggle),
+        "SELECTTASK" => Ok(SelectTask),
+        "SHOWALLWINDOWS" => Ok(ShowAllWindows),
+        "ZOOMTOGGLE" => Ok(ZoomToggle),
 
         _ => Err(AcceleratorParseError::UnsupportedKey(key.to_string())),
     }
@@ -437,3 +652,270 @@ fn test_equality() {
             && h5.id() != h6.id()
     );
 }
+
+#[test]
+fn test_parse_key_round_trip() {
+    use Code::*;
+
+    // Every `Code` except `Unidentified` should be parsed back from its `Display` output.
+    let codes = [
+        Backquote,
+        Backslash,
+        BracketLeft,
+        BracketRight,
+        Comma,
+        Digit0,
+        Digit1,
+        Digit2,
+        Digit3,
+        Digit4,
+        Digit5,
+        Digit6,
+        Digit7,
+        Digit8,
+        Digit9,
+        Equal,
+        IntlBackslash,
+        IntlRo,
+        IntlYen,
+        KeyA,
+        KeyB,
+        KeyC,
+        KeyD,
+        KeyE,
+        KeyF,
+        KeyG,
+        KeyH,
+        KeyI,
+        KeyJ,
+        KeyK,
+        KeyL,
+        KeyM,
+        KeyN,
+        KeyO,
+        KeyP,
+        KeyQ,
+        KeyR,
+        KeyS,
+        KeyT,
+        KeyU,
+        KeyV,
+        KeyW,
+        KeyX,
+        KeyY,
+        KeyZ,
+        Minus,
+        Period,
+        Quote,
+        Semicolon,
+        Slash,
+        AltLeft,
+        AltRight,
+        Backspace,
+        CapsLock,
+        ContextMenu,
+        ControlLeft,
+        ControlRight,
+        Enter,
+        MetaLeft,
+        MetaRight,
+        ShiftLeft,
+        ShiftRight,
+        Space,
+        Tab,
+        Convert,
+        KanaMode,
+        Lang1,
+        Lang2,
+        Lang3,
+        Lang4,
+        Lang5,
+        NonConvert,
+        Delete,
+        End,
+        Help,
+        Home,
+        Insert,
+        PageDown,
+        PageUp,
+        ArrowDown,
+        ArrowLeft,
+        ArrowRight,
+        ArrowUp,
+        NumLock,
+        Numpad0,
+        Numpad1,
+        Numpad2,
+        Numpad3,
+        Numpad4,
+        Numpad5,
+        Numpad6,
+        Numpad7,
+        Numpad8,
+        Numpad9,
+        NumpadAdd,
+        NumpadBackspace,
+        NumpadClear,
+        NumpadClearEntry,
+        NumpadComma,
+        NumpadDecimal,
+        NumpadDivide,
+        NumpadEnter,
+        NumpadEqual,
+        NumpadHash,
+        NumpadMemoryAdd,
+        NumpadMemoryClear,
+        NumpadMemoryRecall,
+        NumpadMemoryStore,
+        NumpadMemorySubtract,
+        NumpadMultiply,
+        NumpadParenLeft,
+        NumpadParenRight,
+        NumpadStar,
+        NumpadSubtract,
+        Escape,
+        Fn,
+        FnLock,
+        PrintScreen,
+        ScrollLock,
+        Pause,
+        BrowserBack,
+        BrowserFavorites,
+        BrowserForward,
+        BrowserHome,
+        BrowserRefresh,
+        BrowserSearch,
+        BrowserStop,
+        Eject,
+        LaunchApp1,
+        LaunchApp2,
+        LaunchMail,
+        MediaPlayPause,
+        MediaSelect,
+        MediaStop,
+        MediaTrackNext,
+        MediaTrackPrevious,
+        Power,
+        Sleep,
+        AudioVolumeDown,
+        AudioVolumeMute,
+        AudioVolumeUp,
+        WakeUp,
+        Hyper,
+        Super,
+        Turbo,
+        Abort,
+        Resume,
+        Suspend,
+        Again,
+        Copy,
+        Cut,
+        Find,
+        Open,
+        Paste,
+        Props,
+        Select,
+        Undo,
+        Hiragana,
+        Katakana,
+        F1,
+        F2,
+        F3,
+        F4,
+        F5,
+        F6,
+        F7,
+        F8,
+        F9,
+        F10,
+        F11,
+        F12,
+        F13,
+        F14,
+        F15,
+        F16,
+        F17,
+        F18,
+        F19,
+        F20,
+        F21,
+        F22,
+        F23,
+        F24,
+        F25,
+        F26,
+        F27,
+        F28,
+        F29,
+        F30,
+        F31,
+        F32,
+        F33,
+        F34,
+        F35,
+        BrightnessDown,
+        BrightnessUp,
+        DisplayToggleIntExt,
+        KeyboardLayoutSelect,
+        LaunchAssistant,
+        LaunchControlPanel,
+        LaunchScreenSaver,
+        MailForward,
+        MailReply,
+        MailSend,
+        MediaFastForward,
+        MediaPause,
+        MediaPlay,
+        MediaRecord,
+        MediaRewind,
+        MicrophoneMuteToggle,
+        PrivacyScreenToggle,
+        SelectTask,
+        ShowAllWindows,
+        ZoomToggle,
+    ];
+
+    for code in codes {
+        let key = code.to_string();
+        assert_eq!(parse_key(&key).unwrap(), code, "failed to parse \"{key}\"");
+        assert_eq!(parse_accelerator(&key).unwrap().key, code);
+
+        // `Super` is also a modifier name, so it can only be used as a key on its own.
+        if code != Super {
+            let accelerator = parse_accelerator(&format!("Shift+{key}")).unwrap();
+            assert_eq!(accelerator.mods, Modifiers::SHIFT);
+            assert_eq!(accelerator.key, code);
+        }
+    }
+
+    assert!(matches!(
+        parse_key("Unidentified"),
+        Err(AcceleratorParseError::UnsupportedKey(_))
+    ));
+}
+
+#[test]
+fn accelerator_label() {
+    let accelerator: Accelerator = "CmdOrCtrl+Shift+KeyP".parse().unwrap();
+    #[cfg(target_os = "macos")]
+    assert_eq!(accelerator.label(), "⇧⌘P");
+    #[cfg(not(target_os = "macos"))]
+    assert_eq!(accelerator.label(), "Ctrl+Shift+P");
+
+    assert_eq!(Accelerator::new(None, Code::F5).label(), "F5");
+    assert_eq!(Accelerator::new(None, Code::Digit1).label(), "1");
+    assert_eq!(Accelerator::new(None, Code::Comma).label(), ",");
+    assert_eq!(Accelerator::new(None, Code::Numpad4).label(), "Num 4");
+
+    let accelerator = Accelerator::new(Some(Modifiers::SUPER | Modifiers::ALT), Code::ArrowUp);
+    assert_eq!(accelerator.label_for(Platform::MacOS), "⌥⌘↑");
+    assert_eq!(accelerator.label_for(Platform::Windows), "Alt+Super+Up");
+    assert_eq!(
+        Accelerator::new(None, Code::Insert).label_for(Platform::Linux),
+        "Ins"
+    );
+    assert_eq!(
+        Accelerator::new(None, Code::Insert).label_for(Platform::MacOS),
+        "Insert"
+    );
+}
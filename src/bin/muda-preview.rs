@@ -0,0 +1,77 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Shows a menu description file, see `MenuSpec::from_file`, as the menubar
+//! and the right-click context menu of a window, reloads it when the file changes
+//! and prints every menu event.
+//!
+//! ```sh
+//! cargo run --features preview --bin muda-preview -- menu.toml
+//! ```
+
+#[cfg(target_os = "linux")]
+fn main() {
+    use std::{path::PathBuf, time::Duration};
+
+    use gtk::{gdk, glib, prelude::*};
+    use muda::{
+        dpi::{LogicalPosition, Position},
+        ContextMenu, Menu, MenuEvent,
+    };
+
+    let path = match std::env::args_os().nth(1) {
+        Some(path) => PathBuf::from(path),
+        None => {
+            eprintln!("usage: muda-preview <menu.json|menu.toml>");
+            std::process::exit(2);
+        }
+    };
+
+    gtk::init().expect("failed to initialize gtk");
+
+    let menu = Menu::new();
+    let watcher = menu.watch_spec(&path, |e| eprintln!("error: {e}"));
+
+    let window = gtk::Window::new(gtk::WindowType::Toplevel);
+    window.set_title(&format!("muda-preview - {}", path.display()));
+    window.set_default_size(640, 400);
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    window.add(&vbox);
+    menu.init_for_gtk_window(&window, Some(&vbox))
+        .expect("failed to add the menubar");
+
+    window.add_events(gdk::EventMask::BUTTON_PRESS_MASK);
+    window.connect_button_press_event({
+        let menu = menu.clone();
+        move |window, event| {
+            if event.button() == 3 {
+                let (x, y) = event.position();
+                menu.show_context_menu_for_gtk_window(
+                    window.upcast_ref(),
+                    Some(Position::Logical(LogicalPosition::new(x, y))),
+                );
+            }
+            glib::Propagation::Proceed
+        }
+    });
+    window.connect_destroy(|_| gtk::main_quit());
+    window.show_all();
+
+    MenuEvent::set_event_handler(Some(|event: MenuEvent| println!("event: {}", event.id.0)));
+
+    glib::timeout_add_local(Duration::from_millis(500), move || {
+        if watcher.poll() {
+            println!("reloaded {}", watcher.path().display());
+        }
+        glib::ControlFlow::Continue
+    });
+
+    gtk::main();
+}
+
+#[cfg(not(target_os = "linux"))]
+fn main() {
+    eprintln!("muda-preview is only supported on Linux");
+    std::process::exit(1);
+}
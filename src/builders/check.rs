@@ -12,6 +12,7 @@ pub struct CheckMenuItemBuilder {
     checked: bool,
     accelerator: Option<Accelerator>,
     id: Option<MenuId>,
+    text_key: Option<String>,
 }
 
 impl CheckMenuItemBuilder {
@@ -33,6 +34,14 @@ impl CheckMenuItemBuilder {
         self
     }
 
+    /// Set the translation key for this check menu item.
+    ///
+    /// See [`CheckMenuItem::set_text_key`] for more info.
+    pub fn text_key<S: Into<String>>(mut self, key: S) -> Self {
+        self.text_key.replace(key.into());
+        self
+    }
+
     /// Enable or disable this menu item.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.enabled = enabled;
@@ -59,10 +68,12 @@ impl CheckMenuItemBuilder {
 
     /// Build this check menu item.
     pub fn build(self) -> CheckMenuItem {
-        if let Some(id) = self.id {
+        let item = if let Some(id) = self.id {
             CheckMenuItem::with_id(id, self.text, self.enabled, self.checked, self.accelerator)
         } else {
             CheckMenuItem::new(self.text, self.enabled, self.checked, self.accelerator)
-        }
+        };
+        item.set_text_key(self.text_key.as_deref());
+        item
     }
 }
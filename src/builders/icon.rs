@@ -17,6 +17,7 @@ pub struct IconMenuItemBuilder {
     accelerator: Option<Accelerator>,
     icon: Option<Icon>,
     native_icon: Option<NativeIcon>,
+    text_key: Option<String>,
 }
 
 impl IconMenuItemBuilder {
@@ -38,6 +39,14 @@ impl IconMenuItemBuilder {
         self
     }
 
+    /// Set the translation key for this icon menu item.
+    ///
+    /// See [`IconMenuItem::set_text_key`] for more info.
+    pub fn text_key<S: Into<String>>(mut self, key: S) -> Self {
+        self.text_key.replace(key.into());
+        self
+    }
+
     /// Enable or disable this menu item.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.enabled = enabled;
@@ -72,7 +81,7 @@ impl IconMenuItemBuilder {
 
     /// Build this icon menu item.
     pub fn build(self) -> IconMenuItem {
-        if let Some(id) = self.id {
+        let item = if let Some(id) = self.id {
             if self.icon.is_some() {
                 IconMenuItem::with_id(id, self.text, self.enabled, self.icon, self.accelerator)
             } else {
@@ -93,6 +102,8 @@ impl IconMenuItemBuilder {
                 self.native_icon,
                 self.accelerator,
             )
-        }
+        };
+        item.set_text_key(self.text_key.as_deref());
+        item
     }
 }
@@ -11,6 +11,7 @@ pub struct MenuItemBuilder {
     enabled: bool,
     id: Option<MenuId>,
     accelerator: Option<Accelerator>,
+    text_key: Option<String>,
 }
 
 impl MenuItemBuilder {
@@ -32,6 +33,14 @@ impl MenuItemBuilder {
         self
     }
 
+    /// Set the translation key for this menu item.
+    ///
+    /// See [`MenuItem::set_text_key`] for more info.
+    pub fn text_key<S: Into<String>>(mut self, key: S) -> Self {
+        self.text_key.replace(key.into());
+        self
+    }
+
     /// Enable or disable this menu item.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.enabled = enabled;
@@ -52,10 +61,12 @@ impl MenuItemBuilder {
 
     /// Build this menu item.
     pub fn build(self) -> MenuItem {
-        if let Some(id) = self.id {
+        let item = if let Some(id) = self.id {
             MenuItem::with_id(id, self.text, self.enabled, self.accelerator)
         } else {
             MenuItem::new(self.text, self.enabled, self.accelerator)
-        }
+        };
+        item.set_text_key(self.text_key.as_deref());
+        item
     }
 }
@@ -0,0 +1,65 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{accelerator::Accelerator, PredefinedMenuItem, PredefinedMenuItemType};
+
+/// A builder type for [`PredefinedMenuItem`]
+#[derive(Clone, Debug)]
+pub struct PredefinedMenuItemBuilder {
+    item_type: PredefinedMenuItemType,
+    text: Option<String>,
+    accelerator: Option<Option<Accelerator>>,
+    text_key: Option<String>,
+}
+
+impl PredefinedMenuItemBuilder {
+    pub fn new(item_type: PredefinedMenuItemType) -> Self {
+        Self {
+            item_type,
+            text: None,
+            accelerator: None,
+            text_key: None,
+        }
+    }
+
+    /// Set the text for this predefined menu item, replacing its default text.
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text.replace(text.into());
+        self
+    }
+
+    /// Set the translation key for this predefined menu item.
+    ///
+    /// See [`PredefinedMenuItem::set_text_key`] for more info.
+    pub fn text_key<S: Into<String>>(mut self, key: S) -> Self {
+        self.text_key.replace(key.into());
+        self
+    }
+
+    /// Set this predefined menu item accelerator, replacing its default one,
+    /// pass `None` to remove it.
+    ///
+    /// See [`PredefinedMenuItem::set_accelerator`] for more info.
+    pub fn accelerator<A: TryInto<Accelerator>>(
+        mut self,
+        accelerator: Option<A>,
+    ) -> crate::Result<Self>
+    where
+        crate::Error: From<<A as TryInto<Accelerator>>::Error>,
+    {
+        self.accelerator
+            .replace(accelerator.map(|a| a.try_into()).transpose()?);
+        Ok(self)
+    }
+
+    /// Build this predefined menu item.
+    pub fn build(self) -> PredefinedMenuItem {
+        let accelerator = self
+            .accelerator
+            .unwrap_or_else(|| self.item_type.accelerator());
+        let item = PredefinedMenuItem::with_accelerator(self.item_type, self.text, accelerator);
+        item.set_text_key(self.text_key.as_deref());
+        item
+    }
+}
@@ -11,6 +11,7 @@ pub struct SubmenuBuilder<'a> {
     enabled: bool,
     id: Option<MenuId>,
     items: Vec<&'a dyn IsMenuItem>,
+    text_key: Option<String>,
 }
 
 impl std::fmt::Debug for SubmenuBuilder<'_> {
@@ -18,6 +19,7 @@ impl std::fmt::Debug for SubmenuBuilder<'_> {
         f.debug_struct("SubmenuBuilder")
             .field("text", &self.text)
             .field("enabled", &self.enabled)
+            .field("text_key", &self.text_key)
             .finish()
     }
 }
@@ -41,6 +43,14 @@ impl<'a> SubmenuBuilder<'a> {
         self
     }
 
+    /// Set the translation key for this submenu.
+    ///
+    /// See [`Submenu::set_text_key`] for more info.
+    pub fn text_key<S: Into<String>>(mut self, key: S) -> Self {
+        self.text_key.replace(key.into());
+        self
+    }
+
     /// Enable or disable this submenu.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.enabled = enabled;
@@ -61,10 +71,12 @@ impl<'a> SubmenuBuilder<'a> {
 
     /// Build this menu item.
     pub fn build(self) -> crate::Result<Submenu> {
-        if let Some(id) = self.id {
-            Submenu::with_id_and_items(id, self.text, self.enabled, &self.items)
+        let submenu = if let Some(id) = self.id {
+            Submenu::with_id_and_items(id, self.text, self.enabled, &self.items)?
         } else {
-            Submenu::with_items(self.text, self.enabled, &self.items)
-        }
+            Submenu::with_items(self.text, self.enabled, &self.items)?
+        };
+        submenu.set_text_key(self.text_key.as_deref());
+        Ok(submenu)
     }
 }
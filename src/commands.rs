@@ -0,0 +1,275 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    accelerator::Accelerator, items::PredefinedMenuItemType, mnemonic, MenuId, MenuItemKind,
+};
+
+/// A menu item that can be activated, listed by [`Menu::commands`](crate::Menu::commands).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+    /// The id of the item.
+    pub id: MenuId,
+    /// The text of the submenus leading to the item, followed by the text of the item,
+    /// as shown to the user, see [`MenuItem::display_text`](crate::MenuItem::display_text).
+    pub path: Vec<String>,
+    /// The accelerator of the item.
+    pub accelerator: Option<Accelerator>,
+    /// Whether the item and all the submenus leading to it are enabled.
+    pub enabled: bool,
+    /// Whether the item is checked, `None` if it is not a [`CheckMenuItem`](crate::CheckMenuItem).
+    pub checked: Option<bool>,
+}
+
+impl Command {
+    /// Returns the text of the item, the last element of [`Command::path`].
+    pub fn text(&self) -> &str {
+        self.path.last().map(String::as_str).unwrap_or_default()
+    }
+
+    /// Returns the path to the item joined with `›`, like `Edit › Find › Replace`.
+    pub fn display_path(&self) -> String {
+        self.path.join(" › ")
+    }
+
+    /// Returns the accelerator of the item as shown to the user, see [`Accelerator::label`].
+    pub fn accelerator_label(&self) -> Option<String> {
+        self.accelerator.as_ref().map(Accelerator::label)
+    }
+}
+
+pub(crate) fn collect(
+    items: &[MenuItemKind],
+    path: &mut Vec<String>,
+    enabled: bool,
+) -> Vec<Command> {
+    let mut commands = Vec::new();
+    for item in items {
+        let child = item.child();
+        let text = mnemonic::display_text(child.text());
+        let item_enabled = enabled && child.is_enabled();
+
+        let checked = match item {
+            MenuItemKind::Submenu(submenu) => {
+                drop(child);
+                path.push(text);
+                commands.extend(collect(&submenu.items(), path, item_enabled));
+                path.pop();
+                continue;
+            }
+            MenuItemKind::Predefined(p) => match p.predefined_item_type() {
+                PredefinedMenuItemType::Separator | PredefinedMenuItemType::Services => continue,
+                item_type if !item_type.is_supported() => continue,
+                _ => None,
+            },
+            MenuItemKind::Check(_) => Some(child.is_checked()),
+            _ => None,
+        };
+
+        let mut item_path = path.clone();
+        item_path.push(text);
+        commands.push(Command {
+            id: item.id().clone(),
+            path: item_path,
+            accelerator: child.accelerator(),
+            enabled: item_enabled,
+            checked,
+        });
+    }
+    commands
+}
+
+/// Ranks [`Command`]s against a query for command palettes, see [`CommandIndex::search`].
+///
+/// ```no_run
+/// # use muda::{CommandIndex, Menu};
+/// # let menu = Menu::new();
+/// let index = CommandIndex::new(menu.commands());
+/// for command in index.search("fnd rep") {
+///     println!("{}\t{}", command.display_path(), command.accelerator_label().unwrap_or_default());
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CommandIndex {
+    commands: Vec<Command>,
+    /// The lowercased characters of the path of each command,
+    /// and the index at which the text of the item starts.
+    haystacks: Vec<(Vec<char>, usize)>,
+}
+
+impl CommandIndex {
+    /// Creates an index of `commands`.
+    pub fn new(commands: Vec<Command>) -> Self {
+        let haystacks = commands
+            .iter()
+            .map(|command| {
+                let parents = command.path[..command.path.len().saturating_sub(1)]
+                    .iter()
+                    .map(|p| p.chars().count() + 1)
+                    .sum();
+                let chars = command.path.join(" ").chars().map(lowercase).collect();
+                (chars, parents)
+            })
+            .collect();
+        Self {
+            commands,
+            haystacks,
+        }
+    }
+
+    /// Returns the indexed commands, in menu order.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Returns the commands whose path contains the characters of `query` in order,
+    /// ignoring case and whitespace, from the best match to the worst.
+    ///
+    /// Matches on the first letter of words, on consecutive characters
+    /// and in the text of the item rather than of its submenus rank higher,
+    /// ties keep the menu order. An empty query returns all commands.
+    pub fn search(&self, query: &str) -> Vec<&Command> {
+        let query = query
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(lowercase)
+            .collect::<Vec<_>>();
+
+        let mut matches = self
+            .commands
+            .iter()
+            .zip(&self.haystacks)
+            .filter_map(|(command, (haystack, text_start))| {
+                score(&query, haystack, *text_start).map(|score| (score, command))
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+        matches.into_iter().map(|(_, command)| command).collect()
+    }
+}
+
+impl From<Vec<Command>> for CommandIndex {
+    fn from(commands: Vec<Command>) -> Self {
+        Self::new(commands)
+    }
+}
+
+fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+const MATCH: i32 = 16;
+const WORD_START: i32 = 8;
+const CONSECUTIVE: i32 = 8;
+const IN_TEXT: i32 = 4;
+const GAP: i32 = 1;
+
+/// Returns the best score of matching all of `query` in order in `haystack`, if it matches.
+fn score(query: &[char], haystack: &[char], text_start: usize) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    const NONE: i32 = i32::MIN / 2;
+    let bonus = |j: usize| {
+        let word_start = j == 0 || !haystack[j - 1].is_alphanumeric();
+        MATCH + if word_start { WORD_START } else { 0 } + if j >= text_start { IN_TEXT } else { 0 }
+    };
+
+    // best[j] is the best score with the current query character matched at `j`
+    let mut best = haystack
+        .iter()
+        .enumerate()
+        .map(|(j, c)| if *c == query[0] { bonus(j) } else { NONE })
+        .collect::<Vec<_>>();
+
+    for q in &query[1..] {
+        let mut next = vec![NONE; haystack.len()];
+        // the best of `best[k] - GAP * (j - k - 1)` over `k < j - 1`, stored as `best[k] + GAP * k`
+        let mut gapped = NONE;
+        for j in 1..haystack.len() {
+            if j >= 2 {
+                gapped = gapped.max(best[j - 2] + GAP * (j as i32 - 2));
+            }
+            if haystack[j] == *q {
+                let previous = (best[j - 1] + CONSECUTIVE).max(gapped - GAP * (j as i32 - 1));
+                if previous > NONE / 2 {
+                    next[j] = previous + bonus(j);
+                }
+            }
+        }
+        best = next;
+    }
+
+    best.into_iter().max().filter(|s| *s > NONE / 2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::CommandIndex;
+    use crate::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn commands_and_search() {
+        let find = Submenu::with_items(
+            "&Find",
+            true,
+            &[
+                &MenuItem::with_id(
+                    "find",
+                    "&Find...",
+                    true,
+                    Some("CmdOrCtrl+KeyF".parse().unwrap()),
+                ),
+                &MenuItem::with_id("replace", "&Replace...", true, None),
+            ],
+        )
+        .unwrap();
+        let edit = Submenu::with_items(
+            "&Edit",
+            true,
+            &[
+                &find,
+                &PredefinedMenuItem::separator(),
+                &CheckMenuItem::with_id("wrap", "&Word Wrap", true, true, None),
+            ],
+        )
+        .unwrap();
+        let disabled = Submenu::with_items(
+            "&Tools",
+            false,
+            &[&MenuItem::with_id("format", "&Format", true, None)],
+        )
+        .unwrap();
+        let menu = Menu::with_items(&[&edit, &disabled]).unwrap();
+
+        let commands = menu.commands();
+        assert_eq!(
+            commands.iter().map(|c| c.id.0.as_str()).collect::<Vec<_>>(),
+            ["find", "replace", "wrap", "format"]
+        );
+        assert_eq!(commands[1].display_path(), "Edit › Find › Replace...");
+        assert!(commands[0].accelerator_label().is_some());
+        assert_eq!(commands[2].checked, Some(true));
+        assert_eq!(commands[0].checked, None);
+        assert!(!commands[3].enabled);
+
+        let index = CommandIndex::new(commands);
+        let ids = |query: &str| {
+            index
+                .search(query)
+                .into_iter()
+                .map(|c| c.id.0.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids("").len(), 4);
+        assert_eq!(ids("rep"), ["replace"]);
+        assert_eq!(ids("FIND")[0], "find");
+        assert_eq!(ids("ww"), ["wrap"]);
+        assert_eq!(ids("f")[0], "find");
+        assert_eq!(ids("fmt"), ["format"]);
+        assert!(ids("xyz").is_empty());
+    }
+}
@@ -24,6 +24,10 @@ pub enum Error {
     #[cfg(target_os = "linux")]
     #[error("This menu has already been initialized for this gtk window`")]
     AlreadyInitialized,
+    #[error("There is no group `{0}` in this menu")]
+    UnknownGroup(String),
+    #[error("Invalid menu description: {0}")]
+    InvalidSpec(String),
     #[error(transparent)]
     AcceleratorParseError(#[from] AcceleratorParseError),
 }
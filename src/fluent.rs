@@ -0,0 +1,148 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::borrow::Borrow;
+
+use fluent_bundle::{bundle::FluentBundle, memoizer::MemoizerKind, FluentResource};
+
+/// A menu item label resolved from a Fluent message.
+///
+/// The value of the message is the text of the item, and two optional attributes are read:
+///
+/// ```ftl
+/// file-save = Save As…
+///     .mnemonic = A
+///     .tooltip = Save the document under a new name
+/// ```
+///
+/// - `mnemonic`: the character to use as the mnemonic, marked in the text with `&`,
+///   or appended in parentheses like `Save As… (&A)` if the text doesn't contain it.
+///   Without it, the value is used as is, so translators can also write `Save &As…` directly.
+/// - `tooltip`: a longer description of the item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FluentLabel {
+    /// The text of the item, with its mnemonic.
+    pub text: String,
+    /// The `tooltip` attribute of the message.
+    ///
+    /// Menu items have no tooltips, so this is not used by muda,
+    /// but apps can show it elsewhere, like in a status bar.
+    pub tooltip: Option<String>,
+}
+
+impl FluentLabel {
+    /// Resolves the message with `id` in `bundle`, or returns `None` if it doesn't exist or has no value.
+    ///
+    /// Formatting errors are ignored, Fluent still produces a best-effort text for them.
+    pub fn resolve<R, M>(bundle: &FluentBundle<R, M>, id: &str) -> Option<Self>
+    where
+        R: Borrow<FluentResource>,
+        M: MemoizerKind,
+    {
+        let message = bundle.get_message(id)?;
+        let mut errors = Vec::new();
+        let mut format = |pattern| {
+            bundle
+                .format_pattern(pattern, None, &mut errors)
+                .to_string()
+        };
+
+        let text = format(message.value()?);
+        let mnemonic = message
+            .get_attribute("mnemonic")
+            .and_then(|a| format(a.value()).trim().chars().next());
+        let tooltip = message.get_attribute("tooltip").map(|a| format(a.value()));
+
+        Some(Self {
+            text: match mnemonic {
+                Some(mnemonic) => with_mnemonic(&text, mnemonic),
+                None => text,
+            },
+            tooltip,
+        })
+    }
+}
+
+/// Escapes the `&` in `text` and marks the first occurrence of `mnemonic` in it.
+fn with_mnemonic(text: &str, mnemonic: char) -> String {
+    let mut out = String::with_capacity(text.len() + 5);
+    let mut marked = false;
+    for c in text.chars() {
+        if !marked && crate::mnemonic::same_mnemonic(c, mnemonic) {
+            out.push('&');
+            marked = true;
+        }
+        out.push(c);
+        if c == '&' {
+            out.push('&');
+        }
+    }
+
+    if !marked {
+        out.push_str(" (&");
+        out.extend(mnemonic.to_uppercase());
+        out.push(')');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use fluent_bundle::{FluentBundle, FluentResource};
+
+    use super::FluentLabel;
+    use crate::{MenuItemSpec, MenuSpec};
+
+    const FTL: &str = "
+file = Datei
+    .mnemonic = d
+file-save = Speichern & schließen
+    .mnemonic = S
+    .tooltip = Speichert das Dokument
+file-quit = Beenden
+    .mnemonic = x
+edit = &Bearbeiten
+";
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn relocalize_with_fluent() {
+        let mut bundle = FluentBundle::new(vec!["de".parse().unwrap()]);
+        bundle
+            .add_resource(FluentResource::try_new(FTL.to_string()).unwrap())
+            .unwrap();
+
+        let save = FluentLabel::resolve(&bundle, "file-save").unwrap();
+        assert_eq!(save.text, "&Speichern && schließen");
+        assert_eq!(save.tooltip.as_deref(), Some("Speichert das Dokument"));
+        assert!(FluentLabel::resolve(&bundle, "missing").is_none());
+
+        let menu = MenuSpec::new(vec![
+            MenuItemSpec::submenu(
+                "file",
+                "&File",
+                true,
+                vec![
+                    MenuItemSpec::menu_item("quit", "&Quit", true, None).text_key("file-quit"),
+                    MenuItemSpec::menu_item("close", "&Close", true, None).text_key("missing"),
+                ],
+            )
+            .text_key("file"),
+            MenuItemSpec::submenu("edit", "&Edit", true, vec![]).text_key("edit"),
+        ])
+        .build()
+        .unwrap();
+
+        menu.relocalize_fluent(&bundle);
+        let items = menu.items();
+        let file = items[0].as_submenu_unchecked();
+        assert_eq!(file.text(), "&Datei");
+        assert_eq!(
+            file.items()[0].as_menuitem_unchecked().text(),
+            "Beenden (&X)"
+        );
+        assert_eq!(file.items()[1].as_menuitem_unchecked().text(), "&Close");
+        assert_eq!(items[1].as_submenu_unchecked().text(), "&Bearbeiten");
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::collections::HashMap;
+
+use crate::{util::Container, IsMenuItem, MenuId, MenuItemKind, PredefinedMenuItem};
+
+/// Named groups of items inside a [`Menu`](crate::Menu) or [`Submenu`](crate::Submenu).
+///
+/// Groups are kept in the order they were declared and every item
+/// added through a group remembers its group and priority.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct MenuGroups {
+    order: Vec<String>,
+    members: HashMap<MenuId, (String, i32)>,
+}
+
+impl MenuGroups {
+    /// Declares `group` after all known groups, does nothing if it is already known.
+    pub fn declare(&mut self, group: &str) {
+        if !self.is_declared(group) {
+            self.order.push(group.to_string());
+        }
+    }
+
+    /// Returns whether `group` was declared.
+    pub fn is_declared(&self, group: &str) -> bool {
+        self.order.iter().any(|g| g == group)
+    }
+
+    fn priority_in(&self, item: &MenuItemKind, group: &str) -> Option<i32> {
+        self.members
+            .get(item.id())
+            .filter(|(g, _)| g == group)
+            .map(|(_, priority)| *priority)
+    }
+
+    /// Returns the position for a new item of `group` with `priority` among `items`.
+    fn position(&self, items: &[MenuItemKind], group: &str, priority: i32) -> usize {
+        let members = items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| self.priority_in(item, group).map(|p| (i, p)))
+            .collect::<Vec<_>>();
+
+        if let Some((last, _)) = members.last() {
+            return members
+                .iter()
+                .find(|(_, p)| *p > priority)
+                .map(|(i, _)| *i)
+                .unwrap_or(last + 1);
+        }
+
+        // the group is empty, place it relative to its neighbouring groups
+        let at = self.order.iter().position(|g| g == group).unwrap_or(0);
+        let first_of = |g: &str| items.iter().position(|i| self.priority_in(i, g).is_some());
+        let last_of = |g: &str| items.iter().rposition(|i| self.priority_in(i, g).is_some());
+
+        self.order[at + 1..]
+            .iter()
+            .find_map(|g| first_of(g))
+            .or_else(|| {
+                self.order[..at]
+                    .iter()
+                    .rev()
+                    .find_map(|g| last_of(g).map(|i| i + 1))
+            })
+            .unwrap_or(items.len())
+    }
+}
+
+pub(crate) fn insert_in_group(
+    container: Container,
+    item: &dyn IsMenuItem,
+    group: &str,
+    priority: i32,
+) -> crate::Result<()> {
+    let items = container.items();
+    let position = container.with_groups(|groups| {
+        // forget items that were removed since they were added
+        groups
+            .members
+            .retain(|id, _| items.iter().any(|i| i.id() == id));
+        groups.declare(group);
+        groups.position(&items, group, priority)
+    });
+
+    container.insert(item, position)?;
+    container.with_groups(|groups| {
+        groups
+            .members
+            .insert(item.id().clone(), (group.to_string(), priority))
+    });
+
+    Ok(())
+}
+
+/// Inserts a separator at the start of every group with items, except the first one.
+pub(crate) fn separate_groups(container: Container) -> crate::Result<()> {
+    let items = container.items();
+    let groups = container.with_groups(|g| {
+        g.order
+            .iter()
+            .filter(|group| items.iter().any(|i| g.priority_in(i, group).is_some()))
+            .skip(1)
+            .cloned()
+            .collect::<Vec<_>>()
+    });
+
+    for group in groups {
+        insert_in_group(
+            container,
+            &PredefinedMenuItem::separator(),
+            &group,
+            i32::MIN,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Adds `items` to `container`, merging submenus with the same id
+/// and placing grouped items by the group and priority they have in `groups`.
+pub(crate) fn merge(
+    container: Container,
+    items: Vec<MenuItemKind>,
+    groups: MenuGroups,
+) -> crate::Result<()> {
+    container.with_groups(|g| {
+        for group in &groups.order {
+            g.declare(group);
+        }
+    });
+
+    let existing = container.items();
+    for item in items {
+        match (existing.iter().find(|i| i.id() == item.id()), &item) {
+            (Some(MenuItemKind::Submenu(target)), MenuItemKind::Submenu(source)) => merge(
+                Container::Submenu(target),
+                source.items(),
+                source.with_groups(|g| g.clone()),
+            )?,
+            // already contributed
+            (Some(_), _) => {}
+            (None, _) => match groups.members.get(item.id()) {
+                Some((group, priority)) => {
+                    insert_in_group(container, item.as_ref(), group, *priority)?
+                }
+                None => container.insert(item.as_ref(), container.items().len())?,
+            },
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Menu, MenuId, MenuItem, Submenu};
+
+    fn ids(submenu: &Submenu) -> Vec<MenuId> {
+        submenu.items().iter().map(|i| i.id().clone()).collect()
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn groups_and_merge() {
+        let item = |id: &str| MenuItem::with_id(id, id, true, None);
+
+        let host = Menu::new();
+        let edit = Submenu::with_id("edit", "Edit", true);
+        host.append(&edit).unwrap();
+        edit.append(&item("undo")).unwrap();
+        edit.add_group("edit.clipboard");
+        edit.add_group("edit.transform");
+        edit.insert_in_group(&item("copy"), "edit.clipboard", 0)
+            .unwrap();
+
+        let plugin = Menu::new();
+        let edit2 = Submenu::with_id("edit", "Edit", true);
+        plugin.append(&edit2).unwrap();
+        edit2
+            .insert_in_group(&item("lower"), "edit.transform", 20)
+            .unwrap();
+        edit2
+            .insert_in_group(&item("upper"), "edit.transform", 10)
+            .unwrap();
+        edit2
+            .insert_in_group(&item("cut"), "edit.clipboard", -1)
+            .unwrap();
+        plugin.append(&item("tools")).unwrap();
+
+        host.merge(&plugin).unwrap();
+        host.merge(&plugin).unwrap();
+
+        assert_eq!(
+            ids(&edit),
+            ["undo", "cut", "copy", "upper", "lower"].map(MenuId::new)
+        );
+        assert_eq!(host.items().len(), 2);
+        assert_eq!(host.items()[1].id(), "tools");
+    }
+}
@@ -4,7 +4,10 @@
 
 use std::{cell::RefCell, mem, rc::Rc};
 
-use crate::{accelerator::Accelerator, sealed::IsMenuItemBase, IsMenuItem, MenuId, MenuItemKind};
+use crate::{
+    accelerator::Accelerator, sealed::IsMenuItemBase, IsMenuItem, MenuId, MenuItemKind,
+    NewIdStrategy,
+};
 
 /// A check menu item inside a [`Menu`] or [`Submenu`]
 /// and usually contains a text and a check mark or a similar toggle
@@ -91,6 +94,23 @@ impl CheckMenuItem {
         self.inner.borrow().text()
     }
 
+    /// Get the text for this check menu item as shown to the user, without the mnemonic markers,
+    /// like `Select All` for `Select &All`, see [`mnemonic::strip`].
+    ///
+    /// [`mnemonic::strip`]: crate::mnemonic::strip
+    pub fn display_text(&self) -> String {
+        crate::mnemonic::display_text(self.text())
+    }
+
+    /// Get the mnemonic of this check menu item, the character following the first single `&` of its text.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **macOS:** Always `None`, mnemonics are not supported and are stripped from the text.
+    pub fn mnemonic(&self) -> Option<char> {
+        crate::mnemonic::item_mnemonic(&self.text())
+    }
+
     /// Set the text for this check menu item. `text` could optionally contain
     /// an `&` before a character to assign this character as the mnemonic
     /// for this check menu item. To display a `&` without assigning a mnemenonic, use `&&`.
@@ -98,6 +118,20 @@ impl CheckMenuItem {
         self.inner.borrow_mut().set_text(text.as_ref())
     }
 
+    /// Returns the translation key of this check menu item, see [`CheckMenuItem::set_text_key`].
+    pub fn text_key(&self) -> Option<String> {
+        self.inner.borrow().text_key()
+    }
+
+    /// Set the translation key of this check menu item, used by [`Menu::relocalize`] to update its text.
+    ///
+    /// [`Menu::relocalize`]: crate::Menu::relocalize
+    pub fn set_text_key(&self, key: Option<&str>) {
+        self.inner
+            .borrow_mut()
+            .set_text_key(key.map(ToString::to_string))
+    }
+
     /// Get whether this check menu item is enabled or not.
     pub fn is_enabled(&self) -> bool {
         self.inner.borrow().is_enabled()
@@ -123,6 +157,23 @@ impl CheckMenuItem {
         self.inner.borrow_mut().set_checked(checked)
     }
 
+    /// Creates an independent copy of this check menu item, its id is chosen by `new_id_strategy`.
+    pub fn deep_clone(&self, new_id_strategy: &NewIdStrategy) -> Self {
+        let inner = self.inner.borrow();
+        let mut item = crate::platform_impl::MenuChild::new_check(
+            &inner.text(),
+            inner.is_enabled(),
+            inner.is_checked(),
+            inner.accelerator(),
+            new_id_strategy.new_id(self.id()),
+        );
+        item.set_text_key(inner.text_key());
+        Self {
+            id: Rc::new(item.id().clone()),
+            inner: Rc::new(RefCell::new(item)),
+        }
+    }
+
     /// Convert this menu item into its menu ID.
     pub fn into_id(mut self) -> MenuId {
         // Note: `Rc::into_inner` is available from Rust 1.70
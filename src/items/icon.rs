@@ -8,7 +8,7 @@ use crate::{
     accelerator::Accelerator,
     icon::{Icon, NativeIcon},
     sealed::IsMenuItemBase,
-    IsMenuItem, MenuId, MenuItemKind,
+    IsMenuItem, MenuId, MenuItemKind, NewIdStrategy,
 };
 
 /// An icon menu item inside a [`Menu`] or [`Submenu`]
@@ -150,6 +150,23 @@ impl IconMenuItem {
         self.inner.borrow().text()
     }
 
+    /// Get the text for this icon menu item as shown to the user, without the mnemonic markers,
+    /// like `Select All` for `Select &All`, see [`mnemonic::strip`].
+    ///
+    /// [`mnemonic::strip`]: crate::mnemonic::strip
+    pub fn display_text(&self) -> String {
+        crate::mnemonic::display_text(self.text())
+    }
+
+    /// Get the mnemonic of this icon menu item, the character following the first single `&` of its text.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **macOS:** Always `None`, mnemonics are not supported and are stripped from the text.
+    pub fn mnemonic(&self) -> Option<char> {
+        crate::mnemonic::item_mnemonic(&self.text())
+    }
+
     /// Set the text for this check menu item. `text` could optionally contain
     /// an `&` before a character to assign this character as the mnemonic
     /// for this check menu item. To display a `&` without assigning a mnemenonic, use `&&`.
@@ -157,6 +174,20 @@ impl IconMenuItem {
         self.inner.borrow_mut().set_text(text.as_ref())
     }
 
+    /// Returns the translation key of this icon menu item, see [`IconMenuItem::set_text_key`].
+    pub fn text_key(&self) -> Option<String> {
+        self.inner.borrow().text_key()
+    }
+
+    /// Set the translation key of this icon menu item, used by [`Menu::relocalize`] to update its text.
+    ///
+    /// [`Menu::relocalize`]: crate::Menu::relocalize
+    pub fn set_text_key(&self, key: Option<&str>) {
+        self.inner
+            .borrow_mut()
+            .set_text_key(key.map(ToString::to_string))
+    }
+
     /// Get whether this check menu item is enabled or not.
     pub fn is_enabled(&self) -> bool {
         self.inner.borrow().is_enabled()
@@ -187,6 +218,41 @@ impl IconMenuItem {
         self.inner.borrow_mut().set_native_icon(_icon)
     }
 
+    /// Creates an independent copy of this icon menu item, its id is chosen by `new_id_strategy`.
+    pub fn deep_clone(&self, new_id_strategy: &NewIdStrategy) -> Self {
+        let inner = self.inner.borrow();
+        let id = new_id_strategy.new_id(self.id());
+
+        #[cfg(target_os = "macos")]
+        if let Some(native_icon) = inner.native_icon() {
+            let mut item = crate::platform_impl::MenuChild::new_native_icon(
+                &inner.text(),
+                inner.is_enabled(),
+                Some(native_icon),
+                inner.accelerator(),
+                id,
+            );
+            item.set_text_key(inner.text_key());
+            return Self {
+                id: Rc::new(item.id().clone()),
+                inner: Rc::new(RefCell::new(item)),
+            };
+        }
+
+        let mut item = crate::platform_impl::MenuChild::new_icon(
+            &inner.text(),
+            inner.is_enabled(),
+            inner.icon(),
+            inner.accelerator(),
+            id,
+        );
+        item.set_text_key(inner.text_key());
+        Self {
+            id: Rc::new(item.id().clone()),
+            inner: Rc::new(RefCell::new(item)),
+        }
+    }
+
     /// Convert this menu item into its menu ID.
     pub fn into_id(mut self) -> MenuId {
         // Note: `Rc::into_inner` is available from Rust 1.70
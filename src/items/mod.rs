@@ -16,7 +16,9 @@ pub use submenu::*;
 
 #[cfg(test)]
 mod test {
-    use crate::{CheckMenuItem, IconMenuItem, MenuId, MenuItem, PredefinedMenuItem, Submenu};
+    use crate::{
+        CheckMenuItem, IconMenuItem, MenuId, MenuItem, NewIdStrategy, PredefinedMenuItem, Submenu,
+    };
 
     #[test]
     #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
@@ -59,4 +61,67 @@ mod test {
         let item = PredefinedMenuItem::separator();
         assert_eq!(item.id().clone(), item.into_id());
     }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn deep_clone_is_independent() {
+        let check = CheckMenuItem::with_id("wrap", "Wrap", true, false, None);
+        let submenu = Submenu::with_id_and_items(
+            "view",
+            "View",
+            true,
+            &[&check, &PredefinedMenuItem::separator()],
+        )
+        .unwrap();
+
+        let copy = submenu
+            .deep_clone(&NewIdStrategy::Suffix("-2".into()))
+            .unwrap();
+        assert_eq!(copy.id(), "view-2");
+        assert_eq!(copy.text(), "View");
+
+        let items = copy.items();
+        assert_eq!(items.len(), 2);
+        let check_copy = items[0].as_check_menuitem_unchecked();
+        assert_eq!(check_copy.id(), "wrap-2");
+
+        check_copy.set_checked(true);
+        assert!(!check.is_checked());
+
+        let copy = submenu.deep_clone(&NewIdStrategy::Fresh).unwrap();
+        assert_ne!(copy.id(), submenu.id());
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn deep_clone_keeps_text_keys() {
+        let item = MenuItem::new("Save", true, None);
+        item.set_text_key(Some("file.save"));
+        let check = CheckMenuItem::new("Wrap", true, false, None);
+        check.set_text_key(Some("view.wrap"));
+        let icon = IconMenuItem::new("Open", true, None, None);
+        icon.set_text_key(Some("file.open"));
+        let copy = PredefinedMenuItem::copy(None);
+        copy.set_text_key(Some("edit.copy"));
+        let submenu = Submenu::with_items("File", true, &[&item, &check, &icon, &copy]).unwrap();
+        submenu.set_text_key(Some("file"));
+
+        let submenu = submenu.deep_clone(&NewIdStrategy::Fresh).unwrap();
+        assert_eq!(submenu.text_key().as_deref(), Some("file"));
+        let keys: Vec<_> = submenu
+            .items()
+            .iter()
+            .map(|item| match item {
+                crate::MenuItemKind::MenuItem(i) => i.text_key(),
+                crate::MenuItemKind::Check(i) => i.text_key(),
+                crate::MenuItemKind::Icon(i) => i.text_key(),
+                crate::MenuItemKind::Predefined(i) => i.text_key(),
+                crate::MenuItemKind::Submenu(i) => i.text_key(),
+            })
+            .collect();
+        assert_eq!(
+            keys,
+            ["file.save", "view.wrap", "file.open", "edit.copy"].map(|k| Some(k.to_string()))
+        );
+    }
 }
@@ -2,12 +2,18 @@
 // SPDX-License-Identifier: Apache-2.inner
 // SPDX-License-Identifier: MIT
 
-use std::{cell::RefCell, mem, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    mem::{self, Discriminant},
+    rc::Rc,
+};
 
 use crate::{
     accelerator::{Accelerator, CMD_OR_CTRL},
     sealed::IsMenuItemBase,
-    AboutMetadata, IsMenuItem, MenuId, MenuItemKind,
+    AboutMetadata, IsMenuItem, LabelProvider, MenuEvent, MenuId, MenuItemKind, NewIdStrategy,
 };
 use keyboard_types::{Code, Modifiers};
 
@@ -77,19 +83,11 @@ impl PredefinedMenuItem {
     }
 
     /// Minimize window menu item
-    ///
-    /// ## Platform-specific:
-    ///
-    /// - **Linux:** Unsupported.
     pub fn minimize(text: Option<&str>) -> PredefinedMenuItem {
         PredefinedMenuItem::new(PredefinedMenuItemType::Minimize, text)
     }
 
     /// Maximize window menu item
-    ///
-    /// ## Platform-specific:
-    ///
-    /// - **Linux:** Unsupported.
     pub fn maximize(text: Option<&str>) -> PredefinedMenuItem {
         PredefinedMenuItem::new(PredefinedMenuItemType::Maximize, text)
     }
@@ -98,16 +96,12 @@ impl PredefinedMenuItem {
     ///
     /// ## Platform-specific:
     ///
-    /// - **Windows / Linux:** Unsupported.
+    /// - **Windows:** Unsupported.
     pub fn fullscreen(text: Option<&str>) -> PredefinedMenuItem {
         PredefinedMenuItem::new(PredefinedMenuItemType::Fullscreen, text)
     }
 
     /// Hide window menu item
-    ///
-    /// ## Platform-specific:
-    ///
-    /// - **Linux:** Unsupported.
     pub fn hide(text: Option<&str>) -> PredefinedMenuItem {
         PredefinedMenuItem::new(PredefinedMenuItemType::Hide, text)
     }
@@ -131,10 +125,6 @@ impl PredefinedMenuItem {
     }
 
     /// Close window menu item
-    ///
-    /// ## Platform-specific:
-    ///
-    /// - **Linux:** Unsupported.
     pub fn close_window(text: Option<&str>) -> PredefinedMenuItem {
         PredefinedMenuItem::new(PredefinedMenuItemType::CloseWindow, text)
     }
@@ -143,7 +133,9 @@ impl PredefinedMenuItem {
     ///
     /// ## Platform-specific:
     ///
-    /// - **Linux:** Unsupported.
+    /// - **Linux:** Calls `gtk::main_quit` when running `gtk::main`. Otherwise, like in apps
+    ///   using tao or winit, it emits a [`MenuEvent`] that the app must handle to quit,
+    ///   or its action can be replaced with [`PredefinedMenuItemType::set_action_handler`].
     pub fn quit(text: Option<&str>) -> PredefinedMenuItem {
         PredefinedMenuItem::new(PredefinedMenuItemType::Quit, text)
     }
@@ -171,15 +163,138 @@ impl PredefinedMenuItem {
         PredefinedMenuItem::new(PredefinedMenuItemType::BringAllToFront, text)
     }
 
-    fn new<S: AsRef<str>>(item: PredefinedMenuItemType, text: Option<S>) -> Self {
-        let item = crate::platform_impl::MenuChild::new_predefined(
-            item,
-            text.map(|t| t.as_ref().to_string()),
-        );
-        Self {
+    /// Delete menu item, deletes the selection of the focused text field.
+    ///
+    /// It has no default accelerator, as a `Delete` accelerator would take the key
+    /// from the focused text field, see [`PredefinedMenuItem::set_accelerator`] to set one.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **Windows:** Sends the `Delete` key to the focused window.
+    pub fn delete(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::Delete, text)
+    }
+
+    /// Find menu item, emits a [`MenuEvent`] when activated.
+    pub fn find(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::Find, text)
+    }
+
+    /// Find next menu item, emits a [`MenuEvent`] when activated.
+    pub fn find_next(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::FindNext, text)
+    }
+
+    /// Preferences (or Settings) menu item, emits a [`MenuEvent`] when activated.
+    pub fn preferences(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::Preferences, text)
+    }
+
+    /// Help menu item, emits a [`MenuEvent`] when activated.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **macOS:** Opens the app's help book instead of emitting a [`MenuEvent`].
+    pub fn help(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::Help, text)
+    }
+
+    /// New window menu item, emits a [`MenuEvent`] when activated.
+    pub fn new_window(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::NewWindow, text)
+    }
+
+    /// Zoom in menu item, emits a [`MenuEvent`] when activated.
+    pub fn zoom_in(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::ZoomIn, text)
+    }
+
+    /// Zoom out menu item, emits a [`MenuEvent`] when activated.
+    pub fn zoom_out(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::ZoomOut, text)
+    }
+
+    /// 'Actual size' menu item which resets the zoom, emits a [`MenuEvent`] when activated.
+    pub fn zoom_reset(text: Option<&str>) -> PredefinedMenuItem {
+        PredefinedMenuItem::new(PredefinedMenuItemType::ZoomReset, text)
+    }
+
+    pub(crate) fn new<S: AsRef<str>>(item: PredefinedMenuItemType, text: Option<S>) -> Self {
+        let accelerator = item.accelerator();
+        Self::with_accelerator(item, text, accelerator)
+    }
+
+    pub(crate) fn with_accelerator<S: AsRef<str>>(
+        item: PredefinedMenuItemType,
+        text: Option<S>,
+        accelerator: Option<Accelerator>,
+    ) -> Self {
+        let track = text.is_none() && !matches!(item, PredefinedMenuItemType::Separator);
+        let text = match text {
+            Some(text) => Some(text.as_ref().to_string()),
+            None if track => Some(crate::labels::default_text(&item, None)),
+            None => None,
+        };
+        let item = crate::platform_impl::MenuChild::new_predefined(item, text, accelerator);
+        let item = Self {
             id: Rc::new(item.id().clone()),
             inner: Rc::new(RefCell::new(item)),
+        };
+        if track {
+            crate::labels::track(&item.inner, None);
         }
+        item
+    }
+
+    /// Set the provider of the default text of predefined menu items, replacing the built-in English text.
+    ///
+    /// Predefined menu items created without a text, on the current thread,
+    /// are relabelled right away unless their text was changed with [`PredefinedMenuItem::set_text`],
+    /// so this can be called again when the app's locale changes.
+    ///
+    /// ```no_run
+    /// use muda::{BundledLabels, PredefinedMenuItem};
+    ///
+    /// if let Some(labels) = BundledLabels::new("de-DE") {
+    ///     PredefinedMenuItem::set_label_provider(labels);
+    /// }
+    /// ```
+    pub fn set_label_provider<P: LabelProvider + 'static>(provider: P) {
+        crate::labels::set_provider(Some(Box::new(provider)))
+    }
+
+    /// Removes the provider set with [`PredefinedMenuItem::set_label_provider`],
+    /// relabelling predefined menu items with the built-in English text.
+    pub fn remove_label_provider() {
+        crate::labels::set_provider(None)
+    }
+
+    /// Restores the default text of this predefined menu item, see [`PredefinedMenuItem::set_label_provider`].
+    pub(crate) fn reset_text(&self) {
+        if crate::labels::is_tracked(&self.inner) {
+            return;
+        }
+        let item_type = self.predefined_item_type();
+        if matches!(item_type, PredefinedMenuItemType::Separator) {
+            return;
+        }
+        self.inner
+            .borrow_mut()
+            .set_text(&crate::labels::default_text(&item_type, None));
+        crate::labels::track(&self.inner, None);
+    }
+
+    /// Puts `app_name` in the default text of this predefined menu item, if it has its default text,
+    /// like `About Foo`, see [`StandardMenuOptions::app_name`](crate::StandardMenuOptions::app_name).
+    pub(crate) fn set_default_app_name(&self, app_name: &str) {
+        if !crate::labels::is_tracked(&self.inner) {
+            return;
+        }
+        let item_type = self.predefined_item_type();
+        self.inner
+            .borrow_mut()
+            .set_text(&crate::labels::default_text(&item_type, Some(app_name)));
+        crate::labels::track(&self.inner, Some(app_name.to_string()));
     }
 
     /// Returns a unique identifier associated with this predefined menu item.
@@ -187,16 +302,121 @@ impl PredefinedMenuItem {
         &self.id
     }
 
+    /// Returns the type of this predefined menu item.
+    pub fn predefined_item_type(&self) -> PredefinedMenuItemType {
+        self.inner
+            .borrow()
+            .predefined_item_type()
+            .expect("predefined menu items always have a type")
+    }
+
+    /// Returns whether this predefined menu item is supported on the current platform.
+    ///
+    /// See [`PredefinedMenuItemType::is_supported`] for more info.
+    pub fn is_supported(&self) -> bool {
+        self.predefined_item_type().is_supported()
+    }
+
     /// Get the text for this predefined menu item.
     pub fn text(&self) -> String {
         self.inner.borrow().text()
     }
 
+    /// Get the text for this predefined menu item as shown to the user, without the mnemonic markers,
+    /// like `Select All` for `Select &All`, see [`mnemonic::strip`].
+    ///
+    /// [`mnemonic::strip`]: crate::mnemonic::strip
+    pub fn display_text(&self) -> String {
+        crate::mnemonic::display_text(self.text())
+    }
+
+    /// Get the mnemonic of this predefined menu item, the character following the first single `&` of its text.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **macOS:** Always `None`, mnemonics are not supported and are stripped from the text.
+    pub fn mnemonic(&self) -> Option<char> {
+        crate::mnemonic::item_mnemonic(&self.text())
+    }
+
     /// Set the text for this predefined menu item.
     pub fn set_text<S: AsRef<str>>(&self, text: S) {
+        crate::labels::untrack(&self.inner);
         self.inner.borrow_mut().set_text(text.as_ref())
     }
 
+    /// Returns the translation key of this predefined menu item, see [`PredefinedMenuItem::set_text_key`].
+    pub fn text_key(&self) -> Option<String> {
+        self.inner.borrow().text_key()
+    }
+
+    /// Set the translation key of this predefined menu item, used by [`Menu::relocalize`] to update its text.
+    ///
+    /// [`Menu::relocalize`]: crate::Menu::relocalize
+    pub fn set_text_key(&self, key: Option<&str>) {
+        self.inner
+            .borrow_mut()
+            .set_text_key(key.map(ToString::to_string))
+    }
+
+    /// Set this predefined menu item accelerator, replacing its default one,
+    /// or remove it by passing `None`.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **Linux:** The default accelerator of Copy, Cut, Paste and Select All is only shown
+    ///   and left for the focused text field to handle, other accelerators are registered as usual.
+    pub fn set_accelerator(&self, accelerator: Option<Accelerator>) -> crate::Result<()> {
+        self.inner.borrow_mut().set_accelerator(accelerator)
+    }
+
+    /// Replaces the native action of this predefined menu item with `handler`,
+    /// which is called with the id of this item when it is activated.
+    ///
+    /// If `emit_event` is `true`, a [`MenuEvent`] is also sent after `handler` is called.
+    ///
+    /// This takes precedence over a handler set with [`PredefinedMenuItemType::set_action_handler`].
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **All:** Separator items can't be overridden.
+    /// - **macOS:** Services items can't be overridden.
+    pub fn set_action_handler<F: Fn(&MenuId) + 'static>(&self, handler: F, emit_event: bool) {
+        self.inner
+            .borrow_mut()
+            .set_action_handler(Some(ActionHandler::new(handler, emit_event)))
+    }
+
+    /// Removes the handler set with [`PredefinedMenuItem::set_action_handler`],
+    /// restoring the global handler for this item type, if any, or the native action.
+    pub fn remove_action_handler(&self) {
+        self.inner.borrow_mut().set_action_handler(None)
+    }
+
+    /// Creates an independent copy of this predefined menu item, with its text, accelerator
+    /// and handler set with [`PredefinedMenuItem::set_action_handler`].
+    ///
+    /// Predefined menu items can't have custom ids, so `new_id_strategy` does not apply
+    /// and the copy always gets a new unique id.
+    pub fn deep_clone(&self, _new_id_strategy: &NewIdStrategy) -> Self {
+        let inner = self.inner.borrow();
+        // a copy of an item with the default text keeps following the label provider
+        let text = if crate::labels::is_tracked(&self.inner) {
+            None
+        } else {
+            Some(inner.text())
+        };
+        let item = Self::with_accelerator(self.predefined_item_type(), text, inner.accelerator());
+        if let Some(app_name) = crate::labels::app_name(&self.inner) {
+            item.set_default_app_name(&app_name);
+        }
+        let mut copy = item.inner.borrow_mut();
+        copy.set_action_handler(inner.action_handler());
+        copy.set_text_key(inner.text_key());
+        drop(copy);
+        item
+    }
+
     /// Convert this menu item into its menu ID.
     pub fn into_id(mut self) -> MenuId {
         // Note: `Rc::into_inner` is available from Rust 1.70
@@ -238,38 +458,105 @@ fn test_about_metadata() {
     );
 }
 
+/// The type of a [`PredefinedMenuItem`], see the matching constructors
+/// on [`PredefinedMenuItem`] for platform-specific notes.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 #[allow(clippy::large_enum_variant)]
-pub(crate) enum PredefinedMenuItemType {
+pub enum PredefinedMenuItemType {
+    /// See [`PredefinedMenuItem::separator`]
     Separator,
+    /// See [`PredefinedMenuItem::copy`]
     Copy,
+    /// See [`PredefinedMenuItem::cut`]
     Cut,
+    /// See [`PredefinedMenuItem::paste`]
     Paste,
+    /// See [`PredefinedMenuItem::select_all`]
     SelectAll,
+    /// See [`PredefinedMenuItem::undo`]
     Undo,
+    /// See [`PredefinedMenuItem::redo`]
     Redo,
+    /// See [`PredefinedMenuItem::minimize`]
     Minimize,
+    /// See [`PredefinedMenuItem::maximize`]
     Maximize,
+    /// See [`PredefinedMenuItem::fullscreen`]
     Fullscreen,
+    /// See [`PredefinedMenuItem::hide`]
     Hide,
+    /// See [`PredefinedMenuItem::hide_others`]
     HideOthers,
+    /// See [`PredefinedMenuItem::show_all`]
     ShowAll,
+    /// See [`PredefinedMenuItem::close_window`]
     CloseWindow,
+    /// See [`PredefinedMenuItem::quit`]
     Quit,
+    /// See [`PredefinedMenuItem::about`]
     About(Option<AboutMetadata>),
+    /// See [`PredefinedMenuItem::services`]
     Services,
+    /// See [`PredefinedMenuItem::bring_all_to_front`]
     BringAllToFront,
-    None,
+    /// See [`PredefinedMenuItem::delete`]
+    Delete,
+    /// See [`PredefinedMenuItem::find`]
+    Find,
+    /// See [`PredefinedMenuItem::find_next`]
+    FindNext,
+    /// See [`PredefinedMenuItem::preferences`]
+    Preferences,
+    /// See [`PredefinedMenuItem::help`]
+    Help,
+    /// See [`PredefinedMenuItem::new_window`]
+    NewWindow,
+    /// See [`PredefinedMenuItem::zoom_in`]
+    ZoomIn,
+    /// See [`PredefinedMenuItem::zoom_out`]
+    ZoomOut,
+    /// See [`PredefinedMenuItem::zoom_reset`]
+    ZoomReset,
 }
 
-impl Default for PredefinedMenuItemType {
-    fn default() -> Self {
-        Self::None
+impl PredefinedMenuItemType {
+    /// Returns whether this predefined menu item is supported on the current platform.
+    ///
+    /// Unsupported items are not shown on Linux and do nothing on Windows,
+    /// see the matching constructors on [`PredefinedMenuItem`] for platform-specific notes.
+    pub fn is_supported(&self) -> bool {
+        self.is_platform_supported()
+    }
+
+    /// Replaces the native action of all predefined menu items of this type with `handler`,
+    /// which is called with the id of the activated item.
+    ///
+    /// If `emit_event` is `true`, a [`MenuEvent`] is also sent after `handler` is called.
+    ///
+    /// Items with their own handler, see [`PredefinedMenuItem::set_action_handler`],
+    /// are not affected. Handlers are registered for the current thread only,
+    /// so this should be called on the thread the menus are used on.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **All:** Separator items can't be overridden.
+    /// - **macOS:** Services items can't be overridden.
+    pub fn set_action_handler<F: Fn(&MenuId) + 'static>(&self, handler: F, emit_event: bool) {
+        GLOBAL_ACTION_HANDLERS.with(|handlers| {
+            handlers.borrow_mut().insert(
+                mem::discriminant(self),
+                ActionHandler::new(handler, emit_event),
+            )
+        });
+    }
+
+    /// Removes the handler set with [`PredefinedMenuItemType::set_action_handler`].
+    pub fn remove_action_handler(&self) {
+        GLOBAL_ACTION_HANDLERS
+            .with(|handlers| handlers.borrow_mut().remove(&mem::discriminant(self)));
     }
-}
 
-impl PredefinedMenuItemType {
     pub(crate) fn text(&self) -> &str {
         match self {
             PredefinedMenuItemType::Separator => "",
@@ -284,7 +571,7 @@ impl PredefinedMenuItemType {
             PredefinedMenuItemType::Maximize => "Zoom",
             #[cfg(not(target_os = "macos"))]
             PredefinedMenuItemType::Maximize => "Ma&ximize",
-            PredefinedMenuItemType::Fullscreen => "Toggle Full Screen",
+            PredefinedMenuItemType::Fullscreen => "Toggle &Full Screen",
             PredefinedMenuItemType::Hide => "&Hide",
             PredefinedMenuItemType::HideOthers => "Hide Others",
             PredefinedMenuItemType::ShowAll => "Show All",
@@ -299,7 +586,26 @@ impl PredefinedMenuItemType {
             PredefinedMenuItemType::About(_) => "&About",
             PredefinedMenuItemType::Services => "Services",
             PredefinedMenuItemType::BringAllToFront => "Bring All to Front",
-            PredefinedMenuItemType::None => "",
+            PredefinedMenuItemType::Delete => "&Delete",
+            #[cfg(target_os = "macos")]
+            PredefinedMenuItemType::Find => "Find…",
+            #[cfg(not(target_os = "macos"))]
+            PredefinedMenuItemType::Find => "&Find...",
+            #[cfg(target_os = "macos")]
+            PredefinedMenuItemType::FindNext => "Find Next",
+            #[cfg(not(target_os = "macos"))]
+            PredefinedMenuItemType::FindNext => "Find &Next",
+            #[cfg(target_os = "macos")]
+            PredefinedMenuItemType::Preferences => "Settings…",
+            #[cfg(windows)]
+            PredefinedMenuItemType::Preferences => "&Options",
+            #[cfg(not(any(windows, target_os = "macos")))]
+            PredefinedMenuItemType::Preferences => "Prefere&nces",
+            PredefinedMenuItemType::Help => "&Help",
+            PredefinedMenuItemType::NewWindow => "&New Window",
+            PredefinedMenuItemType::ZoomIn => "Zoom &In",
+            PredefinedMenuItemType::ZoomOut => "Zoom &Out",
+            PredefinedMenuItemType::ZoomReset => "&Actual Size",
         }
     }
 
@@ -342,7 +648,225 @@ impl PredefinedMenuItemType {
             }
             #[cfg(target_os = "macos")]
             PredefinedMenuItemType::Quit => Some(Accelerator::new(Some(CMD_OR_CTRL), Code::KeyQ)),
+            PredefinedMenuItemType::Find => Some(Accelerator::new(Some(CMD_OR_CTRL), Code::KeyF)),
+            #[cfg(target_os = "macos")]
+            PredefinedMenuItemType::FindNext => {
+                Some(Accelerator::new(Some(CMD_OR_CTRL), Code::KeyG))
+            }
+            #[cfg(not(target_os = "macos"))]
+            PredefinedMenuItemType::FindNext => Some(Accelerator::new(None, Code::F3)),
+            PredefinedMenuItemType::Preferences => {
+                Some(Accelerator::new(Some(CMD_OR_CTRL), Code::Comma))
+            }
+            #[cfg(target_os = "macos")]
+            PredefinedMenuItemType::Help => Some(Accelerator::new(
+                Some(CMD_OR_CTRL | Modifiers::SHIFT),
+                Code::Slash,
+            )),
+            #[cfg(not(target_os = "macos"))]
+            PredefinedMenuItemType::Help => Some(Accelerator::new(None, Code::F1)),
+            PredefinedMenuItemType::NewWindow => Some(Accelerator::new(
+                Some(CMD_OR_CTRL | Modifiers::SHIFT),
+                Code::KeyN,
+            )),
+            PredefinedMenuItemType::ZoomIn => {
+                Some(Accelerator::new(Some(CMD_OR_CTRL), Code::Equal))
+            }
+            PredefinedMenuItemType::ZoomOut => {
+                Some(Accelerator::new(Some(CMD_OR_CTRL), Code::Minus))
+            }
+            PredefinedMenuItemType::ZoomReset => {
+                Some(Accelerator::new(Some(CMD_OR_CTRL), Code::Digit0))
+            }
             _ => None,
         }
     }
 }
+
+thread_local! {
+    static GLOBAL_ACTION_HANDLERS: RefCell<HashMap<Discriminant<PredefinedMenuItemType>, ActionHandler>> =
+        RefCell::new(HashMap::new());
+}
+
+/// A handler replacing the native action of a predefined menu item.
+#[derive(Clone)]
+pub(crate) struct ActionHandler {
+    handler: Rc<dyn Fn(&MenuId)>,
+    emit_event: bool,
+}
+
+impl fmt::Debug for ActionHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActionHandler")
+            .field("emit_event", &self.emit_event)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ActionHandler {
+    fn new<F: Fn(&MenuId) + 'static>(handler: F, emit_event: bool) -> Self {
+        Self {
+            handler: Rc::new(handler),
+            emit_event,
+        }
+    }
+
+    /// Runs `handler`, or the global handler for `item_type` if `handler` is `None`,
+    /// instead of the native action of the predefined menu item `id`.
+    ///
+    /// Returns `false` if there is no handler and the native action should run.
+    pub(crate) fn run(
+        handler: Option<&ActionHandler>,
+        item_type: &PredefinedMenuItemType,
+        id: &MenuId,
+    ) -> bool {
+        // clone the handler so it can register other handlers while running
+        let handler = match handler.cloned().or_else(|| {
+            GLOBAL_ACTION_HANDLERS.with(|handlers| {
+                handlers
+                    .borrow()
+                    .get(&mem::discriminant(item_type))
+                    .cloned()
+            })
+        }) {
+            Some(handler) => handler,
+            None => return false,
+        };
+
+        (handler.handler)(id);
+        if handler.emit_event {
+            MenuEvent::send(MenuEvent { id: id.clone() });
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::{ActionHandler, PredefinedMenuItemType};
+    use crate::{MenuId, PredefinedMenuItem};
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn action_handlers() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let run = |item_type: &PredefinedMenuItemType, id: &str| {
+            ActionHandler::run(None, item_type, &MenuId::new(id))
+        };
+
+        assert!(!run(&PredefinedMenuItemType::Copy, "copy"));
+
+        let c = calls.clone();
+        PredefinedMenuItemType::Copy.set_action_handler(
+            move |id| c.borrow_mut().push(format!("global {}", id.0)),
+            false,
+        );
+        let c = calls.clone();
+        PredefinedMenuItemType::About(None).set_action_handler(
+            move |id| c.borrow_mut().push(format!("about {}", id.0)),
+            false,
+        );
+
+        assert!(run(&PredefinedMenuItemType::Copy, "copy"));
+        assert!(!run(&PredefinedMenuItemType::Paste, "paste"));
+        assert!(run(
+            &PredefinedMenuItemType::About(Some(Default::default())),
+            "about"
+        ));
+
+        // like the backends, run the handler of an item without borrowing it
+        let run_item = |item: &PredefinedMenuItem| {
+            let handler = item.inner.borrow().action_handler();
+            ActionHandler::run(handler.as_ref(), &item.predefined_item_type(), item.id())
+        };
+
+        let item = PredefinedMenuItem::copy(None);
+        let c = calls.clone();
+        let item_ = item.clone();
+        item.set_action_handler(
+            move |id| {
+                c.borrow_mut().push(format!("item {}", id.0));
+                item_.set_text("Copied");
+            },
+            false,
+        );
+        assert!(run_item(&item));
+        assert_eq!(item.text(), "Copied");
+        item.remove_action_handler();
+        assert!(run_item(&item));
+
+        PredefinedMenuItemType::Copy.remove_action_handler();
+        assert!(!run_item(&item));
+
+        let expected = [
+            "global copy".to_string(),
+            "about about".to_string(),
+            format!("item {}", item.id().0),
+            format!("global {}", item.id().0),
+        ];
+        assert_eq!(*calls.borrow(), expected);
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn event_items() {
+        let find = PredefinedMenuItem::find(None);
+        assert!(find.is_supported());
+        assert_eq!(find.text(), PredefinedMenuItemType::Find.text());
+        assert!(PredefinedMenuItemType::ZoomIn.accelerator().is_some());
+        assert!(PredefinedMenuItemType::Delete.accelerator().is_none());
+        assert_eq!(
+            PredefinedMenuItem::preferences(Some("&Settings")).text(),
+            "&Settings"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn accelerators() {
+        let close = crate::PredefinedMenuItemBuilder::new(PredefinedMenuItemType::CloseWindow)
+            .accelerator(None::<&str>)
+            .unwrap()
+            .build();
+        assert_eq!(close.inner.borrow().accelerator(), None);
+
+        let copy = crate::PredefinedMenuItemBuilder::new(PredefinedMenuItemType::Copy)
+            .text("Copy")
+            .build();
+        assert_eq!(
+            copy.inner.borrow().accelerator(),
+            PredefinedMenuItemType::Copy.accelerator()
+        );
+
+        let accelerator = "CmdOrCtrl+Shift+C".parse().unwrap();
+        copy.set_accelerator(Some(accelerator)).unwrap();
+        assert_eq!(copy.inner.borrow().accelerator(), Some(accelerator));
+        assert_eq!(
+            copy.deep_clone(&crate::NewIdStrategy::Fresh)
+                .inner
+                .borrow()
+                .accelerator(),
+            Some(accelerator)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn deep_clone_keeps_default_text_and_handler() {
+        let copy = PredefinedMenuItem::copy(None);
+        copy.set_action_handler(|_| {}, false);
+        let paste = PredefinedMenuItem::paste(Some("Paste!"));
+
+        let copy_clone = copy.deep_clone(&crate::NewIdStrategy::Keep);
+        let paste_clone = paste.deep_clone(&crate::NewIdStrategy::Keep);
+        assert!(copy_clone.inner.borrow().action_handler().is_some());
+        assert!(paste_clone.inner.borrow().action_handler().is_none());
+
+        crate::PredefinedMenuItem::set_label_provider(crate::BundledLabels::new("de").unwrap());
+        assert_eq!(copy_clone.text(), "&Kopieren");
+        assert_eq!(paste_clone.text(), "Paste!");
+        crate::PredefinedMenuItem::remove_label_provider();
+    }
+}
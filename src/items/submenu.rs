@@ -5,8 +5,12 @@
 use std::{cell::RefCell, mem, rc::Rc};
 
 use crate::{
-    dpi::Position, sealed::IsMenuItemBase, util::AddOp, ContextMenu, IsMenuItem, MenuId,
-    MenuItemKind,
+    dpi::Position,
+    group::{insert_in_group, MenuGroups},
+    sealed::IsMenuItemBase,
+    spec::reconcile_items,
+    util::{AddOp, Container},
+    ContextMenu, IsMenuItem, MenuId, MenuItemKind, MenuItemSpec, NewIdStrategy,
 };
 
 /// A menu that can be added to a [`Menu`] or another [`Submenu`].
@@ -92,8 +96,15 @@ impl Submenu {
     }
 
     /// Add a menu item to the end of this menu.
+    ///
+    /// ## Platform-spcific:
+    ///
+    /// - **Linux:** Predefined menu items that are not supported are not shown,
+    ///   see [`PredefinedMenuItem::is_supported`].
+    ///
+    /// [`PredefinedMenuItem::is_supported`]: crate::PredefinedMenuItem::is_supported
     pub fn append(&self, item: &dyn IsMenuItem) -> crate::Result<()> {
-        self.inner.borrow_mut().add_menu_item(item, AddOp::Append)
+        self.add_menu_item(item, AddOp::Append)
     }
 
     /// Add menu items to the end of this submenu. It calls [`Submenu::append`] in a loop.
@@ -107,9 +118,7 @@ impl Submenu {
 
     /// Add a menu item to the beginning of this submenu.
     pub fn prepend(&self, item: &dyn IsMenuItem) -> crate::Result<()> {
-        self.inner
-            .borrow_mut()
-            .add_menu_item(item, AddOp::Insert(0))
+        self.add_menu_item(item, AddOp::Insert(0))
     }
 
     /// Add menu items to the beginning of this submenu.
@@ -121,9 +130,15 @@ impl Submenu {
 
     /// Insert a menu item at the specified `postion` in the submenu.
     pub fn insert(&self, item: &dyn IsMenuItem, position: usize) -> crate::Result<()> {
-        self.inner
-            .borrow_mut()
-            .add_menu_item(item, AddOp::Insert(position))
+        self.add_menu_item(item, AddOp::Insert(position))
+    }
+
+    fn add_menu_item(&self, item: &dyn IsMenuItem, op: AddOp) -> crate::Result<()> {
+        self.inner.borrow_mut().add_menu_item(item, op)?;
+        // inside a batch, the native items are created once it ends
+        #[cfg(target_os = "linux")]
+        crate::platform_impl::defer_gtk_items(&self.inner);
+        Ok(())
     }
 
     /// Insert menu items at the specified `postion` in the submenu.
@@ -135,6 +150,30 @@ impl Submenu {
         Ok(())
     }
 
+    /// Insert a menu item right before the item with the `anchor` id.
+    ///
+    /// Returns [`Error::NotAChildOfThisMenu`] if there is no such item in this submenu.
+    ///
+    /// [`Error::NotAChildOfThisMenu`]: crate::Error::NotAChildOfThisMenu
+    pub fn insert_before(&self, item: &dyn IsMenuItem, anchor: &MenuId) -> crate::Result<()> {
+        let position = self
+            .index_of(anchor)
+            .ok_or(crate::Error::NotAChildOfThisMenu)?;
+        self.insert(item, position)
+    }
+
+    /// Insert a menu item right after the item with the `anchor` id.
+    ///
+    /// Returns [`Error::NotAChildOfThisMenu`] if there is no such item in this submenu.
+    ///
+    /// [`Error::NotAChildOfThisMenu`]: crate::Error::NotAChildOfThisMenu
+    pub fn insert_after(&self, item: &dyn IsMenuItem, anchor: &MenuId) -> crate::Result<()> {
+        let position = self
+            .index_of(anchor)
+            .ok_or(crate::Error::NotAChildOfThisMenu)?;
+        self.insert(item, position + 1)
+    }
+
     /// Remove a menu item from this submenu.
     pub fn remove(&self, item: &dyn IsMenuItem) -> crate::Result<()> {
         self.inner.borrow_mut().remove(item)
@@ -142,14 +181,70 @@ impl Submenu {
 
     /// Remove the menu item at the specified position from this submenu and returns it.
     pub fn remove_at(&self, position: usize) -> Option<MenuItemKind> {
-        let mut items = self.items();
-        if items.len() > position {
-            let item = items.remove(position);
-            let _ = self.remove(item.as_ref());
-            Some(item)
-        } else {
-            None
+        let item = self.inner.borrow().item_at(position)?;
+        let _ = self.remove(item.as_ref());
+        Some(item)
+    }
+
+    /// Remove all menu items from this submenu.
+    pub fn clear(&self) -> crate::Result<()> {
+        self.inner.borrow_mut().clear()
+    }
+
+    /// Replace all menu items of this submenu with `items`.
+    ///
+    /// See [`Menu::replace_items`] for more info.
+    ///
+    /// [`Menu::replace_items`]: crate::Menu::replace_items
+    pub fn replace_items(&self, items: &[&dyn IsMenuItem]) -> crate::Result<()> {
+        crate::util::replace_items(Container::Submenu(self), items)
+    }
+
+    /// Replace the menu item at the specified `position` with `item` and returns the replaced item.
+    ///
+    /// Returns [`Error::NotAChildOfThisMenu`] if `position` is out of bounds.
+    ///
+    /// See [`Menu::replace_at`] for more info.
+    ///
+    /// [`Menu::replace_at`]: crate::Menu::replace_at
+    /// [`Error::NotAChildOfThisMenu`]: crate::Error::NotAChildOfThisMenu
+    pub fn replace_at(
+        &self,
+        position: usize,
+        item: &dyn IsMenuItem,
+    ) -> crate::Result<MenuItemKind> {
+        let old = self
+            .inner
+            .borrow()
+            .item_at(position)
+            .ok_or(crate::Error::NotAChildOfThisMenu)?;
+        self.remove(old.as_ref())?;
+        self.insert(item, position)?;
+        Ok(old)
+    }
+
+    /// Moves the menu item at position `from` to position `to` in this submenu.
+    ///
+    /// Unlike [`remove`](Self::remove) followed by [`insert`](Self::insert),
+    /// the native menu items are kept as is and only reordered.
+    ///
+    /// Returns [`Error::NotAChildOfThisMenu`] if any of the positions is out of bounds.
+    ///
+    /// [`Error::NotAChildOfThisMenu`]: crate::Error::NotAChildOfThisMenu
+    pub fn move_item(&self, from: usize, to: usize) -> crate::Result<()> {
+        self.inner.borrow_mut().move_item(from, to)
+    }
+
+    /// Swaps the menu items at positions `a` and `b` in this submenu.
+    ///
+    /// See [`move_item`](Self::move_item) for more info.
+    pub fn swap(&self, a: usize, b: usize) -> crate::Result<()> {
+        let (a, b) = (a.min(b), a.max(b));
+        if a == b {
+            return self.move_item(a, b);
         }
+        self.move_item(b, a)?;
+        self.move_item(a + 1, b)
     }
 
     /// Returns a list of menu items that has been added to this submenu.
@@ -157,11 +252,78 @@ impl Submenu {
         self.inner.borrow().items()
     }
 
+    /// Returns the position of the item with `id` in this submenu.
+    pub fn index_of(&self, id: &MenuId) -> Option<usize> {
+        self.items().iter().position(|i| i.id() == id)
+    }
+
+    /// Returns `true` if this submenu has an item with `id`.
+    pub fn contains(&self, id: &MenuId) -> bool {
+        self.index_of(id).is_some()
+    }
+
+    /// Updates the items of this submenu in place to match `items`.
+    ///
+    /// See [`Menu::reconcile`] for more info.
+    ///
+    /// [`Menu::reconcile`]: crate::Menu::reconcile
+    pub fn reconcile(&self, items: &[MenuItemSpec]) -> crate::Result<()> {
+        crate::util::batch(|| reconcile_items(Container::Submenu(self), items))
+    }
+
+    /// Declares a named group of items in this submenu.
+    ///
+    /// See [`Menu::add_group`] for more info.
+    ///
+    /// [`Menu::add_group`]: crate::Menu::add_group
+    pub fn add_group(&self, group: &str) {
+        self.with_groups(|groups| groups.declare(group))
+    }
+
+    /// Insert a menu item into `group`, ordered by `priority`.
+    ///
+    /// See [`Menu::insert_in_group`] for more info.
+    ///
+    /// [`Menu::insert_in_group`]: crate::Menu::insert_in_group
+    pub fn insert_in_group(
+        &self,
+        item: &dyn IsMenuItem,
+        group: &str,
+        priority: i32,
+    ) -> crate::Result<()> {
+        insert_in_group(Container::Submenu(self), item, group, priority)
+    }
+
+    pub(crate) fn with_groups<R>(&self, f: impl FnOnce(&mut MenuGroups) -> R) -> R {
+        f(self
+            .inner
+            .borrow_mut()
+            .groups
+            .get_or_insert_with(Default::default))
+    }
+
     /// Get the text for this submenu.
     pub fn text(&self) -> String {
         self.inner.borrow().text()
     }
 
+    /// Get the text for this submenu as shown to the user, without the mnemonic markers,
+    /// like `Select All` for `Select &All`, see [`mnemonic::strip`].
+    ///
+    /// [`mnemonic::strip`]: crate::mnemonic::strip
+    pub fn display_text(&self) -> String {
+        crate::mnemonic::display_text(self.text())
+    }
+
+    /// Get the mnemonic of this submenu, the character following the first single `&` of its text.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **macOS:** Always `None`, mnemonics are not supported and are stripped from the text.
+    pub fn mnemonic(&self) -> Option<char> {
+        crate::mnemonic::item_mnemonic(&self.text())
+    }
+
     /// Set the text for this submenu. `text` could optionally contain
     /// an `&` before a character to assign this character as the mnemonic
     /// for this submenu. To display a `&` without assigning a mnemenonic, use `&&`.
@@ -169,6 +331,30 @@ impl Submenu {
         self.inner.borrow_mut().set_text(text.as_ref())
     }
 
+    /// Returns the translation key of this submenu, see [`Submenu::set_text_key`].
+    pub fn text_key(&self) -> Option<String> {
+        self.inner.borrow().text_key()
+    }
+
+    /// Set the translation key of this submenu, used by [`Menu::relocalize`] to update its text.
+    ///
+    /// [`Menu::relocalize`]: crate::Menu::relocalize
+    pub fn set_text_key(&self, key: Option<&str>) {
+        self.inner
+            .borrow_mut()
+            .set_text_key(key.map(ToString::to_string))
+    }
+
+    /// Gives a mnemonic to the items of this submenu and of its submenus that don't have one,
+    /// picking a letter that isn't used by another item of the same menu, see [`mnemonic::assign`].
+    ///
+    /// Predefined menu items are left untouched.
+    ///
+    /// [`mnemonic::assign`]: crate::mnemonic::assign
+    pub fn auto_assign_mnemonics(&self) {
+        crate::util::batch(|| crate::mnemonic::auto_assign(&self.items()))
+    }
+
     /// Get whether this submenu is enabled or not.
     pub fn is_enabled(&self) -> bool {
         self.inner.borrow().is_enabled()
@@ -199,6 +385,28 @@ impl Submenu {
         self.inner.borrow_mut().set_as_help_menu_for_nsapp()
     }
 
+    /// Creates an independent copy of this submenu and all of its items,
+    /// their ids are chosen by `new_id_strategy`.
+    pub fn deep_clone(&self, new_id_strategy: &NewIdStrategy) -> crate::Result<Self> {
+        let submenu = {
+            let inner = self.inner.borrow();
+            let id = new_id_strategy.new_id(self.id());
+            let mut submenu =
+                crate::platform_impl::MenuChild::new_submenu(&inner.text(), inner.is_enabled(), id);
+            submenu.set_text_key(inner.text_key());
+            Self {
+                id: Rc::new(submenu.id().clone()),
+                inner: Rc::new(RefCell::new(submenu)),
+            }
+        };
+
+        for item in self.items() {
+            submenu.append(item.deep_clone(new_id_strategy)?.as_ref())?;
+        }
+
+        Ok(submenu)
+    }
+
     /// Convert this submenu into its menu ID.
     pub fn into_id(mut self) -> MenuId {
         // Note: `Rc::into_inner` is available from Rust 1.70
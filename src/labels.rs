@@ -0,0 +1,298 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use crate::{platform_impl::MenuChild, PredefinedMenuItemType};
+
+/// Provides the default text of predefined menu items, see [`PredefinedMenuItem::set_label_provider`].
+///
+/// Implemented for closures and [`BundledLabels`].
+///
+/// [`PredefinedMenuItem::set_label_provider`]: crate::PredefinedMenuItem::set_label_provider
+pub trait LabelProvider {
+    /// Returns the text for predefined menu items of `item_type`,
+    /// or `None` to use the built-in English text.
+    fn label(&self, item_type: &PredefinedMenuItemType) -> Option<String>;
+}
+
+impl<F: Fn(&PredefinedMenuItemType) -> Option<String>> LabelProvider for F {
+    fn label(&self, item_type: &PredefinedMenuItemType) -> Option<String> {
+        self(item_type)
+    }
+}
+
+/// Translations of the predefined menu item texts bundled with muda.
+#[derive(Debug, Clone, Copy)]
+pub struct BundledLabels {
+    labels: Option<&'static Labels>,
+}
+
+impl BundledLabels {
+    /// The languages with bundled labels.
+    pub const LOCALES: &'static [&'static str] = &["de", "en", "es", "fr", "it", "ja", "pt", "zh"];
+
+    /// Returns the labels for `locale`, like `"de"` or `"pt-BR"`,
+    /// or `None` if there are no labels for its language.
+    pub fn new(locale: &str) -> Option<Self> {
+        let language = locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let labels = match language.as_str() {
+            "en" => None,
+            "de" => Some(&DE),
+            "es" => Some(&ES),
+            "fr" => Some(&FR),
+            "it" => Some(&IT),
+            "ja" => Some(&JA),
+            "pt" => Some(&PT),
+            "zh" => Some(&ZH),
+            _ => return None,
+        };
+
+        Some(Self { labels })
+    }
+}
+
+impl LabelProvider for BundledLabels {
+    fn label(&self, item_type: &PredefinedMenuItemType) -> Option<String> {
+        let labels = self.labels?;
+        label_index(item_type).map(|i| labels[i].to_string())
+    }
+}
+
+type Labels = [&'static str; 26];
+
+fn label_index(item_type: &PredefinedMenuItemType) -> Option<usize> {
+    let index = match item_type {
+        PredefinedMenuItemType::Copy => 0,
+        PredefinedMenuItemType::Cut => 1,
+        PredefinedMenuItemType::Paste => 2,
+        PredefinedMenuItemType::SelectAll => 3,
+        PredefinedMenuItemType::Undo => 4,
+        PredefinedMenuItemType::Redo => 5,
+        PredefinedMenuItemType::Minimize => 6,
+        PredefinedMenuItemType::Maximize => 7,
+        PredefinedMenuItemType::Fullscreen => 8,
+        PredefinedMenuItemType::Hide => 9,
+        PredefinedMenuItemType::HideOthers => 10,
+        PredefinedMenuItemType::ShowAll => 11,
+        PredefinedMenuItemType::CloseWindow => 12,
+        PredefinedMenuItemType::Quit => 13,
+        PredefinedMenuItemType::About(_) => 14,
+        PredefinedMenuItemType::Services => 15,
+        PredefinedMenuItemType::BringAllToFront => 16,
+        PredefinedMenuItemType::Delete => 17,
+        PredefinedMenuItemType::Find => 18,
+        PredefinedMenuItemType::FindNext => 19,
+        PredefinedMenuItemType::Preferences => 20,
+        PredefinedMenuItemType::Help => 21,
+        PredefinedMenuItemType::NewWindow => 22,
+        PredefinedMenuItemType::ZoomIn => 23,
+        PredefinedMenuItemType::ZoomOut => 24,
+        PredefinedMenuItemType::ZoomReset => 25,
+        PredefinedMenuItemType::Separator => return None,
+    };
+    Some(index)
+}
+
+#[rustfmt::skip]
+static DE: Labels = [
+    "&Kopieren", "&Ausschneiden", "&Einfügen", "Alles a&uswählen", "&Rückgängig", "&Wiederholen",
+    "&Minimieren", "Ma&ximieren", "&Vollbild", "Ausb&lenden", "Andere ausblenden", "Alle einblenden",
+    "Fenster &schließen", "&Beenden", "Ü&ber", "Dienste", "Alle nach vorne bringen", "&Löschen",
+    "&Suchen...", "Weitersuc&hen", "Einstellu&ngen", "&Hilfe", "Neues &Fenster", "Ver&größern",
+    "Ver&kleinern", "&Originalgröße",
+];
+
+#[rustfmt::skip]
+static ES: Labels = [
+    "&Copiar", "Cor&tar", "&Pegar", "&Seleccionar todo", "&Deshacer", "&Rehacer",
+    "&Minimizar", "Ma&ximizar", "&Pantalla completa", "&Ocultar", "Ocultar otros", "Mostrar todo",
+    "Ce&rrar ventana", "&Salir", "&Acerca de", "Servicios", "Traer todo al frente", "&Eliminar",
+    "&Buscar...", "Buscar si&guiente", "Pre&ferencias", "A&yuda", "&Nueva ventana", "&Acercar",
+    "A&lejar", "&Tamaño real",
+];
+
+#[rustfmt::skip]
+static FR: Labels = [
+    "&Copier", "Co&uper", "C&oller", "&Tout sélectionner", "&Annuler", "&Rétablir",
+    "&Réduire", "&Agrandir", "&Plein écran", "&Masquer", "Masquer les autres", "Tout afficher",
+    "&Fermer la fenêtre", "&Quitter", "À &propos", "Services", "Tout ramener au premier plan", "&Supprimer",
+    "Rec&hercher...", "Rechercher le sui&vant", "&Préférences", "&Aide", "&Nouvelle fenêtre", "Zoom a&vant",
+    "Zoom a&rrière", "&Taille réelle",
+];
+
+#[rustfmt::skip]
+static IT: Labels = [
+    "&Copia", "&Taglia", "&Incolla", "&Seleziona tutto", "&Annulla", "&Ripeti",
+    "Ri&duci a icona", "Ma&ssimizza", "Schermo i&ntero", "Nascon&di", "Nascondi altre", "Mostra tutte",
+    "&Chiudi finestra", "&Esci", "&Informazioni", "Servizi", "Porta tutto in primo piano", "&Elimina",
+    "Tro&va...", "Trova s&uccessivo", "&Preferenze", "&Guida", "&Nuova finestra", "&Ingrandisci",
+    "&Riduci", "Dimensioni &effettive",
+];
+
+#[rustfmt::skip]
+static JA: Labels = [
+    "コピー(&C)", "切り取り(&T)", "貼り付け(&P)", "すべて選択(&A)", "元に戻す(&U)", "やり直し(&R)",
+    "最小化(&M)", "最大化(&X)", "フルスクリーン(&F)", "隠す(&H)", "ほかを隠す", "すべてを表示",
+    "ウィンドウを閉じる(&L)", "終了(&Q)", "バージョン情報(&A)", "サービス", "すべてを手前に移動", "削除(&D)",
+    "検索(&F)...", "次を検索(&N)", "設定(&S)", "ヘルプ(&H)", "新規ウィンドウ(&N)", "拡大(&I)",
+    "縮小(&O)", "実際のサイズ(&A)",
+];
+
+#[rustfmt::skip]
+static PT: Labels = [
+    "&Copiar", "Recor&tar", "Co&lar", "&Selecionar tudo", "&Desfazer", "&Refazer",
+    "&Minimizar", "Ma&ximizar", "Tela &cheia", "&Ocultar", "Ocultar outros", "Mostrar tudo",
+    "&Fechar janela", "Sa&ir", "&Sobre", "Serviços", "Trazer tudo para a frente", "&Excluir",
+    "Locali&zar...", "Localizar &próximo", "Pre&ferências", "Aj&uda", "&Nova janela", "&Ampliar",
+    "&Reduzir", "&Tamanho real",
+];
+
+#[rustfmt::skip]
+static ZH: Labels = [
+    "复制(&C)", "剪切(&T)", "粘贴(&P)", "全选(&A)", "撤销(&U)", "重做(&R)",
+    "最小化(&M)", "最大化(&X)", "全屏(&F)", "隐藏(&H)", "隐藏其他", "全部显示",
+    "关闭窗口(&L)", "退出(&Q)", "关于(&A)", "服务", "前置全部窗口", "删除(&D)",
+    "查找(&F)...", "查找下一个(&N)", "设置(&S)", "帮助(&H)", "新建窗口(&N)", "放大(&I)",
+    "缩小(&O)", "实际大小(&A)",
+];
+
+/// A predefined menu item created with its default text, and the app name in its text.
+type DefaultLabelled = (Weak<RefCell<MenuChild>>, Option<String>);
+
+thread_local! {
+    static LABEL_PROVIDER: RefCell<Option<Box<dyn LabelProvider>>> = const { RefCell::new(None) };
+
+    static DEFAULT_LABELLED: RefCell<Vec<DefaultLabelled>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns the text of the label provider for `item_type`, if any.
+pub(crate) fn label(item_type: &PredefinedMenuItemType) -> Option<String> {
+    LABEL_PROVIDER.with(|provider| provider.borrow().as_ref()?.label(item_type))
+}
+
+/// Returns the default text of predefined menu items of `item_type`,
+/// from the label provider if any, or the built-in English text,
+/// with `app_name` in it, see `PredefinedMenuItemType::platform_text`.
+pub(crate) fn default_text(item_type: &PredefinedMenuItemType, app_name: Option<&str>) -> String {
+    item_type.platform_text(label(item_type), app_name)
+}
+
+pub(crate) fn set_provider(provider: Option<Box<dyn LabelProvider>>) {
+    LABEL_PROVIDER.with(|p| *p.borrow_mut() = provider);
+    relabel();
+}
+
+/// Remembers `item` to be relabelled with `app_name` when the label provider changes.
+pub(crate) fn track(item: &Rc<RefCell<MenuChild>>, app_name: Option<String>) {
+    untrack(item);
+    DEFAULT_LABELLED.with(|items| items.borrow_mut().push((Rc::downgrade(item), app_name)));
+}
+
+/// Returns whether `item` is relabelled when the label provider changes.
+pub(crate) fn is_tracked(item: &Rc<RefCell<MenuChild>>) -> bool {
+    DEFAULT_LABELLED.with(|items| {
+        items
+            .borrow()
+            .iter()
+            .any(|(i, _)| Weak::ptr_eq(i, &Rc::downgrade(item)))
+    })
+}
+
+/// Returns the app name `item` is relabelled with, see [`track`].
+pub(crate) fn app_name(item: &Rc<RefCell<MenuChild>>) -> Option<String> {
+    DEFAULT_LABELLED.with(|items| {
+        items
+            .borrow()
+            .iter()
+            .find(|(i, _)| Weak::ptr_eq(i, &Rc::downgrade(item)))
+            .and_then(|(_, app_name)| app_name.clone())
+    })
+}
+
+/// Forgets `item`, its text was set explicitly.
+pub(crate) fn untrack(item: &Rc<RefCell<MenuChild>>) {
+    DEFAULT_LABELLED.with(|items| {
+        items
+            .borrow_mut()
+            .retain(|(i, _)| i.strong_count() > 0 && !Weak::ptr_eq(i, &Rc::downgrade(item)))
+    });
+}
+
+/// Updates the text of all predefined menu items created with their default text.
+pub(crate) fn relabel() {
+    let items = DEFAULT_LABELLED.with(|items| {
+        let mut items = items.borrow_mut();
+        items.retain(|(i, _)| i.strong_count() > 0);
+        items
+            .iter()
+            .filter_map(|(i, app_name)| Some((i.upgrade()?, app_name.clone())))
+            .collect::<Vec<_>>()
+    });
+
+    for (item, app_name) in items {
+        let mut item = item.borrow_mut();
+        if let Some(item_type) = item.predefined_item_type() {
+            item.set_text(&default_text(&item_type, app_name.as_deref()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BundledLabels;
+    use crate::{LabelProvider, PredefinedMenuItem, PredefinedMenuItemType};
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn relabel_items() {
+        let copy = PredefinedMenuItem::copy(None);
+        let custom = PredefinedMenuItem::paste(Some("Paste!"));
+        let cut = PredefinedMenuItem::cut(None);
+        cut.set_text("Cut!");
+
+        PredefinedMenuItem::set_label_provider(BundledLabels::new("de-DE").unwrap());
+        assert_eq!(copy.text(), "&Kopieren");
+        assert_eq!(custom.text(), "Paste!");
+        assert_eq!(cut.text(), "Cut!");
+        assert_eq!(
+            PredefinedMenuItem::select_all(None).text(),
+            "Alles a&uswählen"
+        );
+
+        // on macOS, the app name is added to the labels of about, hide and quit
+        let quit = PredefinedMenuItemType::Quit.platform_text(Some("&Beenden".to_string()), None);
+        assert_eq!(
+            PredefinedMenuItem::quit(None).text(),
+            PredefinedMenuItem::quit(Some(&quit)).text()
+        );
+
+        PredefinedMenuItem::set_label_provider(|item_type: &PredefinedMenuItemType| {
+            matches!(item_type, PredefinedMenuItemType::Copy).then(|| "Copy it".to_string())
+        });
+        assert_eq!(copy.text(), "Copy it");
+
+        PredefinedMenuItem::remove_label_provider();
+        assert_eq!(copy.text(), PredefinedMenuItemType::Copy.text());
+
+        assert!(BundledLabels::new("xx").is_none());
+        assert_eq!(
+            BundledLabels::new("en")
+                .unwrap()
+                .label(&PredefinedMenuItemType::Copy),
+            None
+        );
+        for locale in BundledLabels::LOCALES {
+            assert!(BundledLabels::new(locale).is_some());
+        }
+    }
+}
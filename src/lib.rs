@@ -23,7 +23,7 @@
 //!
 //! # Dependencies (Linux Only)
 //!
-//! `gtk` is used for menus and `libxdo` is used to make the predfined `Copy`, `Cut`, `Paste` and `SelectAll` menu items work. Be sure to install following packages before building:
+//! `gtk` is used for menus and `libxdo` is optionally used to make the predfined `Copy`, `Cut`, `Paste` and `SelectAll` menu items work when the focused widget is not a GTK text widget, like a webview. Be sure to install following packages before building:
 //!
 //! #### Arch Linux / Manjaro:
 //!
@@ -159,22 +159,44 @@ use once_cell::sync::{Lazy, OnceCell};
 pub mod about_metadata;
 pub mod accelerator;
 mod builders;
+mod commands;
 mod error;
+#[cfg(feature = "fluent")]
+mod fluent;
+mod group;
 mod icon;
 mod items;
+mod labels;
+mod lint;
 mod menu;
 mod menu_id;
+pub mod mnemonic;
 mod platform_impl;
+mod shortcuts;
+mod spec;
+#[cfg(feature = "spec-file")]
+mod spec_file;
+mod standard;
 mod util;
 
 pub use about_metadata::AboutMetadata;
 pub use builders::*;
+pub use commands::{Command, CommandIndex};
 pub use dpi;
 pub use error::*;
+#[cfg(feature = "fluent")]
+pub use fluent::FluentLabel;
 pub use icon::{BadIcon, Icon, NativeIcon};
 pub use items::*;
+pub use labels::{BundledLabels, LabelProvider};
+pub use lint::{LintRule, LintWarning};
 pub use menu::*;
-pub use menu_id::MenuId;
+pub use menu_id::{MenuId, NewIdStrategy};
+pub use shortcuts::ShortcutFormat;
+pub use spec::*;
+#[cfg(feature = "spec-file")]
+pub use spec_file::SpecWatcher;
+pub use standard::StandardMenuOptions;
 
 /// An enumeration of all available menu types, useful to match against
 /// the items returned from [`Menu::items`] or [`Submenu::items`]
@@ -279,6 +301,17 @@ impl MenuItemKind {
         }
     }
 
+    /// Creates an independent copy of this item, see [`Submenu::deep_clone`].
+    pub fn deep_clone(&self, new_id_strategy: &NewIdStrategy) -> crate::Result<MenuItemKind> {
+        Ok(match self {
+            MenuItemKind::MenuItem(i) => MenuItemKind::MenuItem(i.deep_clone(new_id_strategy)),
+            MenuItemKind::Submenu(i) => MenuItemKind::Submenu(i.deep_clone(new_id_strategy)?),
+            MenuItemKind::Predefined(i) => MenuItemKind::Predefined(i.deep_clone(new_id_strategy)),
+            MenuItemKind::Check(i) => MenuItemKind::Check(i.deep_clone(new_id_strategy)),
+            MenuItemKind::Icon(i) => MenuItemKind::Icon(i.deep_clone(new_id_strategy)),
+        })
+    }
+
     /// Convert this item into its menu ID.
     pub fn into_id(self) -> MenuId {
         match self {
@@ -0,0 +1,275 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    items::PredefinedMenuItemType,
+    mnemonic::{display_text, item_mnemonic, strip as strip_mnemonic, ITEM_MNEMONICS},
+    platform_impl::MenuChild,
+    MenuId, MenuItemKind,
+};
+
+/// A rule checked by [`Menu::lint_with`](crate::Menu::lint_with).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    /// Two different items share the same [`MenuId`].
+    DuplicateId,
+    /// A separator is the first or last item of a menu, or follows another separator.
+    Separators,
+    /// A submenu has no items.
+    EmptySubmenu,
+    /// An item has no mnemonic, see [`MenuItem::set_text`](crate::MenuItem::set_text).
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **macOS:** Never reported, menus have no mnemonics.
+    MissingMnemonic,
+    /// Two items of the same menu use the same mnemonic.
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **macOS:** Never reported, menus have no mnemonics.
+    ConflictingMnemonic,
+    /// A predefined menu item is not supported on the current platform
+    /// and will not be shown or will do nothing.
+    UnsupportedItem,
+}
+
+impl LintRule {
+    /// All the available rules.
+    pub const ALL: &'static [LintRule] = &[
+        LintRule::DuplicateId,
+        LintRule::Separators,
+        LintRule::EmptySubmenu,
+        LintRule::MissingMnemonic,
+        LintRule::ConflictingMnemonic,
+        LintRule::UnsupportedItem,
+    ];
+}
+
+/// A problem found by [`Menu::lint`](crate::Menu::lint).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+    /// The rule that produced this warning.
+    pub rule: LintRule,
+    /// The id of the offending item.
+    pub id: MenuId,
+    /// The text of the submenus leading to the offending item, starting from the root menu.
+    pub path: Vec<String>,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path.join(" > "))?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+struct Linter<'a> {
+    rules: &'a [LintRule],
+    ids: HashMap<MenuId, *const MenuChild>,
+    warnings: Vec<LintWarning>,
+}
+
+impl Linter<'_> {
+    fn warn(&mut self, rule: LintRule, item: &MenuItemKind, path: &[String], message: String) {
+        if self.rules.contains(&rule) {
+            self.warnings.push(LintWarning {
+                rule,
+                id: item.id().clone(),
+                path: path.to_vec(),
+                message,
+            });
+        }
+    }
+
+    fn lint(&mut self, items: &[MenuItemKind], path: &mut Vec<String>) {
+        let is_separator = |item: &MenuItemKind| match item {
+            MenuItemKind::Predefined(i) => {
+                matches!(i.predefined_item_type(), PredefinedMenuItemType::Separator)
+            }
+            _ => false,
+        };
+
+        let mut mnemonics = HashMap::new();
+        for (i, item) in items.iter().enumerate() {
+            let child = &*item.child() as *const MenuChild;
+            let shared = match self.ids.get(item.id()) {
+                Some(other) if *other == child => true,
+                Some(_) => {
+                    self.warn(
+                        LintRule::DuplicateId,
+                        item,
+                        path,
+                        format!("the id `{}` is used by more than one item", item.id().0),
+                    );
+                    false
+                }
+                None => {
+                    self.ids.insert(item.id().clone(), child);
+                    false
+                }
+            };
+
+            if is_separator(item) {
+                let message = if i == 0 {
+                    Some("the menu starts with a separator")
+                } else if i == items.len() - 1 {
+                    Some("the menu ends with a separator")
+                } else if is_separator(&items[i - 1]) {
+                    Some("two consecutive separators")
+                } else {
+                    None
+                };
+                if let Some(message) = message {
+                    self.warn(LintRule::Separators, item, path, message.to_string());
+                }
+                continue;
+            }
+
+            if let MenuItemKind::Predefined(p) = item {
+                let item_type = p.predefined_item_type();
+                if !item_type.is_supported() {
+                    self.warn(
+                        LintRule::UnsupportedItem,
+                        item,
+                        path,
+                        format!("{item_type:?} is not supported on this platform"),
+                    );
+                }
+            }
+
+            let text = item.child().text();
+            let shown = display_text(text.clone());
+            match item_mnemonic(&text) {
+                Some(c) => {
+                    let c = c.to_lowercase().to_string();
+                    if let Some(other) = mnemonics.insert(c.clone(), text.clone()) {
+                        self.warn(
+                            LintRule::ConflictingMnemonic,
+                            item,
+                            path,
+                            format!(
+                                "`{shown}` uses the same mnemonic `{c}` as `{}`",
+                                strip_mnemonic(&other)
+                            ),
+                        );
+                    }
+                }
+                // macOS items have no mnemonics, so none is missing there
+                None if ITEM_MNEMONICS => self.warn(
+                    LintRule::MissingMnemonic,
+                    item,
+                    path,
+                    format!("`{shown}` has no mnemonic"),
+                ),
+                None => {}
+            }
+
+            if let MenuItemKind::Submenu(submenu) = item {
+                let children = submenu.items();
+                if children.is_empty() {
+                    self.warn(
+                        LintRule::EmptySubmenu,
+                        item,
+                        path,
+                        format!("the submenu `{shown}` is empty"),
+                    );
+                }
+
+                // the same submenu added twice is linted once
+                if !shared {
+                    path.push(shown);
+                    self.lint(&children, path);
+                    path.pop();
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn lint(items: &[MenuItemKind], rules: &[LintRule]) -> Vec<LintWarning> {
+    let mut linter = Linter {
+        rules,
+        ids: HashMap::new(),
+        warnings: Vec::new(),
+    };
+    linter.lint(items, &mut Vec::new());
+    linter.warnings
+}
+
+#[cfg(test)]
+mod test {
+    use super::LintRule;
+    use crate::{Menu, MenuItem, PredefinedMenuItem, Submenu};
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn unsupported_items() {
+        let menu = Menu::with_items(&[&PredefinedMenuItem::copy(None)]).unwrap();
+        let services = PredefinedMenuItem::services(None);
+        menu.append(&services).unwrap();
+
+        let unsupported = menu.lint_with(&[LintRule::UnsupportedItem]);
+        assert_eq!(unsupported.len(), usize::from(!services.is_supported()));
+        assert!(PredefinedMenuItem::copy(None).is_supported());
+        #[cfg(target_os = "linux")]
+        assert_eq!(unsupported[0].id, services.id());
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn lint_menu() {
+        let edit = Submenu::with_id("edit", "&Edit", true);
+        let menu = Menu::with_items(&[&Submenu::with_id("file", "&File", true), &edit]).unwrap();
+        edit.append_items(&[
+            &PredefinedMenuItem::separator(),
+            &MenuItem::with_id("copy", "&Copy", true, None),
+            &MenuItem::with_id("cut", "&cut", true, None),
+            &MenuItem::with_id("file", "Find", true, None),
+        ])
+        .unwrap();
+
+        let separator = edit.items()[0].id().0.clone();
+        let warnings = menu
+            .lint()
+            .into_iter()
+            .map(|w| (w.rule, w.id.0))
+            .collect::<Vec<_>>();
+        #[cfg(not(target_os = "macos"))]
+        let expected = [
+            (LintRule::EmptySubmenu, "file"),
+            (LintRule::Separators, separator.as_str()),
+            (LintRule::ConflictingMnemonic, "cut"),
+            (LintRule::DuplicateId, "file"),
+            (LintRule::MissingMnemonic, "file"),
+        ];
+        // macOS has no mnemonics
+        #[cfg(target_os = "macos")]
+        let expected = [
+            (LintRule::EmptySubmenu, "file"),
+            (LintRule::Separators, separator.as_str()),
+            (LintRule::DuplicateId, "file"),
+        ];
+        assert_eq!(warnings, expected.map(|(r, id)| (r, id.to_string())));
+
+        let warnings = menu.lint_with(&[LintRule::ConflictingMnemonic]);
+        #[cfg(not(target_os = "macos"))]
+        {
+            assert_eq!(warnings.len(), 1);
+            assert_eq!(
+                warnings[0].to_string(),
+                "Edit: `cut` uses the same mnemonic `c` as `Copy`"
+            );
+        }
+        #[cfg(target_os = "macos")]
+        assert!(warnings.is_empty());
+    }
+}
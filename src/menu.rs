@@ -4,7 +4,15 @@
 
 use std::{cell::RefCell, rc::Rc};
 
-use crate::{dpi::Position, util::AddOp, ContextMenu, IsMenuItem, MenuId, MenuItemKind};
+use crate::{
+    accelerator::Platform,
+    dpi::Position,
+    group::{insert_in_group, merge, MenuGroups},
+    spec::reconcile_items,
+    util::{AddOp, Container},
+    Command, ContextMenu, IsMenuItem, LintRule, LintWarning, MenuId, MenuItemKind, MenuSpec,
+    NewIdStrategy, ShortcutFormat, StandardMenuOptions,
+};
 
 /// A root menu that can be added to a Window on Windows and Linux
 /// and used as the app global menu on macOS.
@@ -56,6 +64,36 @@ impl Menu {
         Ok(menu)
     }
 
+    /// Creates a platform-conventional menubar made of standard submenus of predefined items.
+    ///
+    /// Each submenu has a [`Submenu::id`] and is split into groups, see [`Menu::add_group`],
+    /// that app-specific items can be added to with [`StandardMenuOptions::item`].
+    /// Groups are separated by separators and submenus that end up empty are left out.
+    ///
+    /// | Submenu    | Groups                                                              |
+    /// | ---------- | ------------------------------------------------------------------- |
+    /// | `"app"`    | `"app.about"`, `"app.preferences"`, `"app.services"`, `"app.hide"`, `"app.quit"` |
+    /// | `"file"`   | `"file.new"`, `"file.close"`                                        |
+    /// | `"edit"`   | `"edit.undo"`, `"edit.clipboard"`, `"edit.find"`, `"edit.preferences"` |
+    /// | `"view"`   | `"view.zoom"`, `"view.fullscreen"`                                  |
+    /// | `"window"` | `"window.main"`, `"window.arrange"`                                 |
+    /// | `"help"`   | `"help.main"`, `"help.about"`                                       |
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **macOS:** Only macOS has the `"app"` submenu, which holds the About, Preferences and Quit items.
+    ///   The `"window"` and `"help"` submenus are set as the app's Windows and Help menus.
+    /// - **Windows / Linux:** Quit is in the `"file"` submenu, About in `"help"` and Preferences in `"edit"`.
+    /// - **Linux:** Unless running `gtk::main`, Quit emits a [`MenuEvent`] the app must handle,
+    ///   see [`PredefinedMenuItem::quit`].
+    ///
+    /// [`Submenu::id`]: crate::Submenu::id
+    /// [`MenuEvent`]: crate::MenuEvent
+    /// [`PredefinedMenuItem::quit`]: crate::PredefinedMenuItem::quit
+    pub fn standard(options: StandardMenuOptions) -> crate::Result<Self> {
+        crate::standard::standard(options)
+    }
+
     /// Returns a unique identifier associated with this menu.
     pub fn id(&self) -> &MenuId {
         &self.id
@@ -66,10 +104,13 @@ impl Menu {
     /// ## Platform-spcific:
     ///
     /// - **macOS:** Only [`Submenu`] can be added to the menu
+    /// - **Linux:** Predefined menu items that are not supported are not shown,
+    ///   see [`PredefinedMenuItem::is_supported`].
     ///
     /// [`Submenu`]: crate::Submenu
+    /// [`PredefinedMenuItem::is_supported`]: crate::PredefinedMenuItem::is_supported
     pub fn append(&self, item: &dyn IsMenuItem) -> crate::Result<()> {
-        self.inner.borrow_mut().add_menu_item(item, AddOp::Append)
+        self.add_menu_item(item, AddOp::Append)
     }
 
     /// Add menu items to the end of this menu. It calls [`Menu::append`] in a loop internally.
@@ -95,9 +136,7 @@ impl Menu {
     ///
     /// [`Submenu`]: crate::Submenu
     pub fn prepend(&self, item: &dyn IsMenuItem) -> crate::Result<()> {
-        self.inner
-            .borrow_mut()
-            .add_menu_item(item, AddOp::Insert(0))
+        self.add_menu_item(item, AddOp::Insert(0))
     }
 
     /// Add menu items to the beginning of this menu. It calls [`Menu::insert_items`] with position of `0` internally.
@@ -119,9 +158,15 @@ impl Menu {
     ///
     /// [`Submenu`]: crate::Submenu
     pub fn insert(&self, item: &dyn IsMenuItem, position: usize) -> crate::Result<()> {
-        self.inner
-            .borrow_mut()
-            .add_menu_item(item, AddOp::Insert(position))
+        self.add_menu_item(item, AddOp::Insert(position))
+    }
+
+    fn add_menu_item(&self, item: &dyn IsMenuItem, op: AddOp) -> crate::Result<()> {
+        self.inner.borrow_mut().add_menu_item(item, op)?;
+        // inside a batch, the native items are created once it ends
+        #[cfg(target_os = "linux")]
+        crate::platform_impl::defer_gtk_items(&self.inner);
+        Ok(())
     }
 
     /// Insert menu items at the specified `postion` in the menu.
@@ -139,6 +184,40 @@ impl Menu {
         Ok(())
     }
 
+    /// Insert a menu item right before the item with the `anchor` id.
+    ///
+    /// Returns [`Error::NotAChildOfThisMenu`] if there is no such item in this menu.
+    ///
+    /// ## Platform-spcific:
+    ///
+    /// - **macOS:** Only [`Submenu`] can be added to the menu
+    ///
+    /// [`Submenu`]: crate::Submenu
+    /// [`Error::NotAChildOfThisMenu`]: crate::Error::NotAChildOfThisMenu
+    pub fn insert_before(&self, item: &dyn IsMenuItem, anchor: &MenuId) -> crate::Result<()> {
+        let position = self
+            .index_of(anchor)
+            .ok_or(crate::Error::NotAChildOfThisMenu)?;
+        self.insert(item, position)
+    }
+
+    /// Insert a menu item right after the item with the `anchor` id.
+    ///
+    /// Returns [`Error::NotAChildOfThisMenu`] if there is no such item in this menu.
+    ///
+    /// ## Platform-spcific:
+    ///
+    /// - **macOS:** Only [`Submenu`] can be added to the menu
+    ///
+    /// [`Submenu`]: crate::Submenu
+    /// [`Error::NotAChildOfThisMenu`]: crate::Error::NotAChildOfThisMenu
+    pub fn insert_after(&self, item: &dyn IsMenuItem, anchor: &MenuId) -> crate::Result<()> {
+        let position = self
+            .index_of(anchor)
+            .ok_or(crate::Error::NotAChildOfThisMenu)?;
+        self.insert(item, position + 1)
+    }
+
     /// Remove a menu item from this menu.
     pub fn remove(&self, item: &dyn IsMenuItem) -> crate::Result<()> {
         self.inner.borrow_mut().remove(item)
@@ -146,14 +225,82 @@ impl Menu {
 
     /// Remove the menu item at the specified position from this menu and returns it.
     pub fn remove_at(&self, position: usize) -> Option<MenuItemKind> {
-        let mut items = self.items();
-        if items.len() > position {
-            let item = items.remove(position);
-            let _ = self.remove(item.as_ref());
-            Some(item)
-        } else {
-            None
+        let item = self.inner.borrow().item_at(position)?;
+        let _ = self.remove(item.as_ref());
+        Some(item)
+    }
+
+    /// Remove all menu items from this menu.
+    pub fn clear(&self) -> crate::Result<()> {
+        self.inner.borrow_mut().clear()
+    }
+
+    /// Replace all menu items of this menu with `items`.
+    ///
+    /// The items of this menu that are also in `items` keep their native menu items
+    /// and are only reordered, like with [`move_item`](Self::move_item),
+    /// the other items are removed and the new ones inserted.
+    ///
+    /// ## Platform-spcific:
+    ///
+    /// - **macOS:** Only [`Submenu`] can be added to the menu
+    ///
+    /// [`Submenu`]: crate::Submenu
+    pub fn replace_items(&self, items: &[&dyn IsMenuItem]) -> crate::Result<()> {
+        crate::util::replace_items(Container::Menu(self), items)
+    }
+
+    /// Replace the menu item at the specified `position` with `item` and returns the replaced item.
+    ///
+    /// Returns [`Error::NotAChildOfThisMenu`] if `position` is out of bounds.
+    ///
+    /// This is the same as [`remove`](Self::remove) followed by [`insert`](Self::insert):
+    /// the native menu item of the replaced item is destroyed and one is created for `item`,
+    /// the other items keep theirs.
+    ///
+    /// ## Platform-spcific:
+    ///
+    /// - **macOS:** Only [`Submenu`] can be added to the menu
+    ///
+    /// [`Submenu`]: crate::Submenu
+    /// [`Error::NotAChildOfThisMenu`]: crate::Error::NotAChildOfThisMenu
+    pub fn replace_at(
+        &self,
+        position: usize,
+        item: &dyn IsMenuItem,
+    ) -> crate::Result<MenuItemKind> {
+        let old = self
+            .inner
+            .borrow()
+            .item_at(position)
+            .ok_or(crate::Error::NotAChildOfThisMenu)?;
+        self.remove(old.as_ref())?;
+        self.insert(item, position)?;
+        Ok(old)
+    }
+
+    /// Moves the menu item at position `from` to position `to` in this menu.
+    ///
+    /// Unlike [`remove`](Self::remove) followed by [`insert`](Self::insert),
+    /// the native menu items are kept as is and only reordered.
+    ///
+    /// Returns [`Error::NotAChildOfThisMenu`] if any of the positions is out of bounds.
+    ///
+    /// [`Error::NotAChildOfThisMenu`]: crate::Error::NotAChildOfThisMenu
+    pub fn move_item(&self, from: usize, to: usize) -> crate::Result<()> {
+        self.inner.borrow_mut().move_item(from, to)
+    }
+
+    /// Swaps the menu items at positions `a` and `b` in this menu.
+    ///
+    /// See [`move_item`](Self::move_item) for more info.
+    pub fn swap(&self, a: usize, b: usize) -> crate::Result<()> {
+        let (a, b) = (a.min(b), a.max(b));
+        if a == b {
+            return self.move_item(a, b);
         }
+        self.move_item(b, a)?;
+        self.move_item(a + 1, b)
     }
 
     /// Returns a list of menu items that has been added to this menu.
@@ -161,6 +308,264 @@ impl Menu {
         self.inner.borrow().items()
     }
 
+    /// Checks this menu and all of its submenus for common mistakes, using all [`LintRule`]s.
+    ///
+    /// ```no_run
+    /// # let menu = muda::Menu::new();
+    /// for warning in menu.lint() {
+    ///     eprintln!("{warning}");
+    /// }
+    /// ```
+    ///
+    /// [`LintRule`]: crate::LintRule
+    pub fn lint(&self) -> Vec<LintWarning> {
+        self.lint_with(LintRule::ALL)
+    }
+
+    /// Checks this menu and all of its submenus for common mistakes, only using `rules`.
+    pub fn lint_with(&self, rules: &[LintRule]) -> Vec<LintWarning> {
+        crate::lint::lint(&self.items(), rules)
+    }
+
+    /// Returns the items of this menu and of its submenus that can be activated, in menu order,
+    /// for example to list them in a command palette, see [`CommandIndex`].
+    ///
+    /// Separators and predefined menu items not supported on the current platform are skipped.
+    ///
+    /// [`CommandIndex`]: crate::CommandIndex
+    pub fn commands(&self) -> Vec<Command> {
+        crate::commands::collect(&self.items(), &mut Vec::new(), true)
+    }
+
+    /// Exports the keyboard shortcuts of this menu and of its submenus as a cheat sheet in `format`,
+    /// grouped by their top-level submenu.
+    ///
+    /// Only items with an accelerator are listed, with the path to them from their top-level submenu
+    /// and their accelerator formatted for the current platform, see [`Accelerator::label`].
+    ///
+    /// [`Accelerator::label`]: crate::accelerator::Accelerator::label
+    pub fn export_shortcuts(&self, format: ShortcutFormat) -> String {
+        self.export_shortcuts_for(format, Platform::current())
+    }
+
+    /// Exports the keyboard shortcuts of this menu like [`Menu::export_shortcuts`],
+    /// with their accelerators formatted for `platform`, see [`Accelerator::label_for`].
+    ///
+    /// Menus described by a [`MenuSpec`] can be exported for the target platform from a build script
+    /// using [`MenuSpec::build`], but accelerators using `CmdOrCtrl` are resolved for the host.
+    ///
+    /// [`Accelerator::label_for`]: crate::accelerator::Accelerator::label_for
+    /// [`MenuSpec::build`]: crate::MenuSpec::build
+    pub fn export_shortcuts_for(&self, format: ShortcutFormat, platform: Platform) -> String {
+        crate::shortcuts::export(self.commands(), format, platform)
+    }
+
+    /// Gives a mnemonic to the items of this menu and of its submenus that don't have one,
+    /// see [`Submenu::auto_assign_mnemonics`].
+    ///
+    /// [`Submenu::auto_assign_mnemonics`]: crate::Submenu::auto_assign_mnemonics
+    pub fn auto_assign_mnemonics(&self) {
+        crate::util::batch(|| crate::mnemonic::auto_assign(&self.items()))
+    }
+
+    /// Returns the position of the item with `id` in this menu.
+    pub fn index_of(&self, id: &MenuId) -> Option<usize> {
+        self.items().iter().position(|i| i.id() == id)
+    }
+
+    /// Returns `true` if this menu has an item with `id`.
+    pub fn contains(&self, id: &MenuId) -> bool {
+        self.index_of(id).is_some()
+    }
+
+    /// Creates an independent copy of this menu and all of its items,
+    /// their ids are chosen by `new_id_strategy`.
+    ///
+    /// The copy is not added to any window.
+    pub fn deep_clone(&self, new_id_strategy: &NewIdStrategy) -> crate::Result<Menu> {
+        let menu = match new_id_strategy.new_id(self.id()) {
+            Some(id) => Menu::with_id(id),
+            None => Menu::new(),
+        };
+
+        for item in self.items() {
+            menu.append(item.deep_clone(new_id_strategy)?.as_ref())?;
+        }
+
+        Ok(menu)
+    }
+
+    /// Runs `f` with this menu, deferring native updates until it returns,
+    /// then applies them in one pass.
+    ///
+    /// Updates that are overwritten inside `f`, like calling [`MenuItem::set_text`] twice,
+    /// are only applied once.
+    ///
+    /// Native updates are queued per thread rather than per menu, so updating an item or
+    /// a submenu inside `f` is also deferred for the other menus and context menus it is in.
+    /// Batches can be nested, updates are applied when the outermost one returns,
+    /// and are thrown away if `f` panics.
+    ///
+    /// ```no_run
+    /// # use muda::{Menu, MenuItem};
+    /// # let menu = Menu::new();
+    /// menu.batch(|m| {
+    ///     for i in 0..10 {
+    ///         m.append(&MenuItem::new(format!("Item {i}"), true, None))?;
+    ///     }
+    ///     Ok::<_, muda::Error>(())
+    /// })?;
+    /// # Ok::<_, muda::Error>(())
+    /// ```
+    ///
+    /// ## Platform-specific:
+    ///
+    /// - **Windows:** Menu bar redraws and accelerator table rebuilds are deferred.
+    /// - **Linux:** Text, enabled and checked state updates of items are deferred,
+    ///   the native widgets of the items added to a menu or a submenu are created
+    ///   for all of its windows and context menus at once, and the native widgets
+    ///   of removed items are destroyed at once.
+    /// - **macOS:** Has no effect, updates are applied immediately.
+    ///
+    /// [`MenuItem::set_text`]: crate::MenuItem::set_text
+    pub fn batch<R>(&self, f: impl FnOnce(&Menu) -> R) -> R {
+        crate::util::batch(|| f(self))
+    }
+
+    /// Updates the text of every item of this menu and its submenus
+    /// that has a translation key to `localize(key)`, see [`MenuItem::set_text_key`].
+    ///
+    /// Only the text of the items is changed, they keep their native widgets.
+    /// Accelerator labels are derived from the accelerator of each item and are not localized,
+    /// and menu items have no tooltips. Items without a translation key are left untouched.
+    ///
+    /// ```no_run
+    /// # use muda::{Menu, MenuItemBuilder};
+    /// let menu = Menu::new();
+    /// let save = MenuItemBuilder::new().text("&Save").text_key("file.save").build();
+    /// menu.append(&save);
+    ///
+    /// menu.relocalize(|key| match key {
+    ///     "file.save" => "&Speichern".to_string(),
+    ///     key => key.to_string(),
+    /// });
+    /// ```
+    ///
+    /// [`MenuItem::set_text_key`]: crate::MenuItem::set_text_key
+    pub fn relocalize<F: Fn(&str) -> String>(&self, localize: F) {
+        crate::util::batch(|| crate::util::relocalize(&self.items(), &|key| Some(localize(key))))
+    }
+
+    /// Updates the text of every item of this menu and its submenus that has a translation key
+    /// to the Fluent message with that id in `bundle`, see [`FluentLabel`] for the supported attributes.
+    ///
+    /// Like [`Menu::relocalize`], only the text of the items is changed, the `tooltip` attribute is ignored.
+    /// Items whose message is missing from `bundle` are left untouched.
+    ///
+    /// [`FluentLabel`]: crate::FluentLabel
+    #[cfg(feature = "fluent")]
+    pub fn relocalize_fluent<R, M>(&self, bundle: &fluent_bundle::bundle::FluentBundle<R, M>)
+    where
+        R: std::borrow::Borrow<fluent_bundle::FluentResource>,
+        M: fluent_bundle::memoizer::MemoizerKind,
+    {
+        crate::util::batch(|| {
+            crate::util::relocalize(&self.items(), &|key| {
+                crate::FluentLabel::resolve(bundle, key).map(|label| label.text)
+            })
+        })
+    }
+
+    /// Updates this menu in place to match `spec`.
+    ///
+    /// Items are matched by their [`MenuId`] and only the differences are applied,
+    /// so unchanged items and their native widgets are kept as is.
+    /// See [`MenuItemSpec`] for how items are matched.
+    ///
+    /// [`MenuItemSpec`]: crate::MenuItemSpec
+    pub fn reconcile(&self, spec: &MenuSpec) -> crate::Result<()> {
+        crate::util::batch(|| reconcile_items(Container::Menu(self), &spec.items))
+    }
+
+    /// Keeps this menu in sync with the menu description file at `path`, see [`MenuSpec::from_file`].
+    ///
+    /// The returned watcher does not get notified of file changes by itself:
+    /// the file is applied right away, then again only when [`SpecWatcher::poll`] is called
+    /// and finds that the file changed. Call it from the event loop on the thread this menu
+    /// is used on, like from a timer every 500 milliseconds. Each call only reads
+    /// the modification time and length of the file, so polling often is cheap.
+    ///
+    /// Changes are applied using [`Menu::reconcile`], so items keep their ids and native widgets
+    /// and event handlers keep working. Errors reading, parsing or applying the file
+    /// are passed to `on_error`, a file that can't be read or parsed leaves the menu as it was.
+    ///
+    /// ```no_run
+    /// # use muda::Menu;
+    /// let menu = Menu::new();
+    /// let watcher = menu.watch_spec("menu.toml", |e| eprintln!("{e}"));
+    ///
+    /// // in the event loop, every 500 milliseconds
+    /// watcher.poll();
+    /// ```
+    ///
+    /// Requires the `spec-file` feature.
+    ///
+    /// [`SpecWatcher::poll`]: crate::SpecWatcher::poll
+    #[cfg(feature = "spec-file")]
+    pub fn watch_spec<P, F>(&self, path: P, on_error: F) -> crate::SpecWatcher
+    where
+        P: AsRef<std::path::Path>,
+        F: FnMut(crate::Error) + 'static,
+    {
+        crate::SpecWatcher::new(self.clone(), path.as_ref().to_path_buf(), on_error)
+    }
+
+    /// Declares a named group of items in this menu, like `"edit.transform"`.
+    ///
+    /// Groups keep the order they were declared in, an empty group is placed
+    /// between the items of its neighbouring groups once it gets its first item.
+    /// Groups are also declared implicitly by [`Menu::insert_in_group`].
+    pub fn add_group(&self, group: &str) {
+        self.with_groups(|groups| groups.declare(group))
+    }
+
+    /// Insert a menu item into `group`, after the items of the group
+    /// with a lower or equal `priority` and before the ones with a higher `priority`.
+    ///
+    /// ## Platform-spcific:
+    ///
+    /// - **macOS:** Only [`Submenu`] can be added to the menu
+    ///
+    /// [`Submenu`]: crate::Submenu
+    pub fn insert_in_group(
+        &self,
+        item: &dyn IsMenuItem,
+        group: &str,
+        priority: i32,
+    ) -> crate::Result<()> {
+        insert_in_group(Container::Menu(self), item, group, priority)
+    }
+
+    /// Adds the items of `other` to this menu.
+    ///
+    /// Submenus with the same id as a submenu of this menu are merged into it recursively,
+    /// other items that already exist in this menu are skipped, so merging the same menu twice is a no-op.
+    /// Items that were added to `other` through [`Menu::insert_in_group`] or [`Submenu::insert_in_group`]
+    /// are inserted into the group with the same name here, ordered by their priority.
+    /// All other items are appended.
+    ///
+    /// The items are shared with `other`, not copied, see [`Menu::deep_clone`] for that.
+    ///
+    /// [`Submenu::insert_in_group`]: crate::Submenu::insert_in_group
+    pub fn merge(&self, other: &Menu) -> crate::Result<()> {
+        let groups = other.with_groups(|g| g.clone());
+        crate::util::batch(|| merge(Container::Menu(self), other.items(), groups))
+    }
+
+    pub(crate) fn with_groups<R>(&self, f: impl FnOnce(&mut MenuGroups) -> R) -> R {
+        f(self.inner.borrow_mut().groups_mut())
+    }
+
     /// Adds this menu to a [`gtk::Window`]
     ///
     /// - `container`: this is an optional paramter to specify a container for the [`gtk::MenuBar`],
@@ -434,3 +839,113 @@ pub enum MenuTheme {
     Light = 1,
     Auto = 2,
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{Menu, MenuId, MenuItem, MenuItemKind, Submenu};
+
+    fn item(id: &str) -> MenuItem {
+        MenuItem::with_id(id, id, true, None)
+    }
+
+    fn ids(items: Vec<MenuItemKind>) -> Vec<MenuId> {
+        items.iter().map(|i| i.id().clone()).collect()
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn insert_relative() {
+        let menu = Menu::with_items(&[&item("undo"), &item("copy")]).unwrap();
+
+        menu.insert_after(&item("redo"), &MenuId::new("undo"))
+            .unwrap();
+        menu.insert_before(&item("cut"), &MenuId::new("copy"))
+            .unwrap();
+        menu.insert_after(&item("paste"), &MenuId::new("copy"))
+            .unwrap();
+        assert_eq!(ids(menu.items()), ["undo", "redo", "cut", "copy", "paste"]);
+
+        assert!(menu
+            .insert_before(&item("x"), &MenuId::new("missing"))
+            .is_err());
+        assert!(menu
+            .insert_after(&item("x"), &MenuId::new("missing"))
+            .is_err());
+        assert!(!menu.contains(&MenuId::new("x")));
+        assert!(menu.contains(&MenuId::new("cut")));
+        assert_eq!(menu.index_of(&MenuId::new("paste")), Some(4));
+        assert_eq!(menu.index_of(&MenuId::new("x")), None);
+
+        let edit = Submenu::with_id_and_items("edit", "Edit", true, &[&item("find")]).unwrap();
+        edit.insert_before(&item("replace"), &MenuId::new("find"))
+            .unwrap();
+        assert_eq!(edit.index_of(&MenuId::new("find")), Some(1));
+        assert!(edit.contains(&MenuId::new("replace")));
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn move_and_swap() {
+        let menu = Menu::with_items(&[&item("a"), &item("b"), &item("c"), &item("d")]).unwrap();
+
+        menu.move_item(0, 3).unwrap();
+        assert_eq!(ids(menu.items()), ["b", "c", "d", "a"]);
+        menu.swap(3, 0).unwrap();
+        assert_eq!(ids(menu.items()), ["a", "c", "d", "b"]);
+        assert!(menu.move_item(0, 4).is_err());
+        assert!(menu.swap(4, 0).is_err());
+
+        let submenu =
+            Submenu::with_id_and_items("s", "S", true, &[&item("x"), &item("y"), &item("z")])
+                .unwrap();
+        submenu.move_item(2, 0).unwrap();
+        submenu.swap(1, 2).unwrap();
+        assert_eq!(ids(submenu.items()), ["z", "y", "x"]);
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn batch_adds_and_removes_items() {
+        let submenu = Submenu::with_id("s", "S", true);
+        let menu = Menu::with_items(&[&item("a"), &submenu]).unwrap();
+
+        let inserted = menu.batch(|menu| {
+            let b = item("b");
+            menu.insert(&b, 0).unwrap();
+            menu.remove(menu.items()[1].as_ref()).unwrap();
+            menu.append(&item("c")).unwrap();
+            submenu.append_items(&[&item("x"), &item("y")]).unwrap();
+            b.set_text("B");
+            b
+        });
+
+        assert_eq!(ids(menu.items()), ["b", "s", "c"]);
+        assert_eq!(submenu.items().len(), 2);
+        assert_eq!(inserted.text(), "B");
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn clear_and_replace() {
+        let submenu = Submenu::with_id("file", "File", true);
+        let menu = Menu::with_items(&[&submenu, &item("b")]).unwrap();
+        submenu.append_items(&[&item("x"), &item("y")]).unwrap();
+
+        let old = menu.replace_at(1, &item("c")).unwrap();
+        assert_eq!(old.id(), "b");
+        assert!(menu.replace_at(2, &item("d")).is_err());
+        assert_eq!(menu.items()[1].id(), "c");
+
+        submenu.clear().unwrap();
+        assert!(submenu.items().is_empty());
+        assert!(submenu.remove_at(0).is_none());
+
+        menu.replace_items(&[&item("d"), &submenu]).unwrap();
+        assert_eq!(ids(menu.items()), ["d", "file"]);
+
+        let (x, y) = (item("x"), item("y"));
+        submenu.append_items(&[&x, &y, &item("z")]).unwrap();
+        submenu.replace_items(&[&y, &item("w"), &x]).unwrap();
+        assert_eq!(ids(submenu.items()), ["y", "w", "x"]);
+    }
+}
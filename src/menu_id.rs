@@ -68,6 +68,34 @@ impl PartialEq<&MenuId> for MenuId {
     }
 }
 
+/// Describes how ids are assigned to the copies made by `deep_clone`,
+/// for example [`Submenu::deep_clone`](crate::Submenu::deep_clone).
+///
+/// Predefined menu items always get new ids.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum NewIdStrategy {
+    /// Generate new unique ids.
+    #[default]
+    Fresh,
+    /// Append a suffix to the original ids, with a `"-2"` suffix, `"save"` becomes `"save-2"`.
+    Suffix(String),
+    /// Keep the original ids, the copies will emit the same [`MenuEvent`](crate::MenuEvent)s
+    /// as the original items.
+    Keep,
+}
+
+impl NewIdStrategy {
+    /// Returns the id for the copy of an item with `id`, `None` means a new unique id.
+    pub(crate) fn new_id(&self, id: &MenuId) -> Option<MenuId> {
+        match self {
+            NewIdStrategy::Fresh => None,
+            NewIdStrategy::Suffix(suffix) => Some(MenuId(format!("{}{}", id.0, suffix))),
+            NewIdStrategy::Keep => Some(id.clone()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::MenuId;
@@ -0,0 +1,325 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Mnemonics are the access keys of menu items, the underlined character
+//! that activates an item when its menu is open.
+//!
+//! The mnemonic of an item is marked in its text with an `&` before a character,
+//! like `&File`, and a literal `&` is escaped as `&&`, like `Tom && &Jerry`.
+//!
+//! # Examples
+//! ```
+//! # use muda::mnemonic;
+//! let label = mnemonic::parse("Save && &Close");
+//! assert_eq!(label.text, "Save & Close");
+//! assert_eq!(label.mnemonic, Some('C'));
+//! assert_eq!(label.index, Some(7));
+//! ```
+
+use crate::MenuItemKind;
+
+/// A menu item text split into the text shown to the user and its mnemonic, see [`parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedText {
+    /// The text shown to the user, without the `&` markers and with `&&` unescaped.
+    pub text: String,
+    /// The mnemonic, the character following the first single `&`.
+    pub mnemonic: Option<char>,
+    /// The index of the mnemonic in the characters of `text`.
+    pub index: Option<usize>,
+}
+
+/// Parses `text` into the text shown to the user and its mnemonic.
+///
+/// Only the first `&` marks the mnemonic, the others are dropped,
+/// and a trailing `&` is kept as is.
+pub fn parse(text: &str) -> ParsedText {
+    let mut parsed = ParsedText {
+        text: String::with_capacity(text.len()),
+        mnemonic: None,
+        index: None,
+    };
+
+    let mut len = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '&' => match chars.next() {
+                Some('&') | None => '&',
+                Some(c) => {
+                    if parsed.mnemonic.is_none() {
+                        parsed.mnemonic = Some(c);
+                        parsed.index = Some(len);
+                    }
+                    c
+                }
+            },
+            c => c,
+        };
+        parsed.text.push(c);
+        len += 1;
+    }
+
+    parsed
+}
+
+/// Returns the mnemonic of `text`, the character following the first single `&`.
+pub fn mnemonic(text: &str) -> Option<char> {
+    parse(text).mnemonic
+}
+
+/// Returns `text` as shown to the user, without the `&` markers and with `&&` unescaped.
+pub fn strip(text: &str) -> String {
+    parse(text).text
+}
+
+/// Whether menu items have mnemonics on the current platform,
+/// the macOS backend strips the mnemonics from the text of its items.
+pub(crate) const ITEM_MNEMONICS: bool = !cfg!(target_os = "macos");
+
+/// Returns the text of a menu item as shown to the user, see [`ITEM_MNEMONICS`].
+pub(crate) fn display_text(text: String) -> String {
+    if ITEM_MNEMONICS {
+        strip(&text)
+    } else {
+        text
+    }
+}
+
+/// Returns the mnemonic of a menu item with `text`, always `None` on macOS, see [`ITEM_MNEMONICS`].
+pub(crate) fn item_mnemonic(text: &str) -> Option<char> {
+    if ITEM_MNEMONICS {
+        mnemonic(text)
+    } else {
+        None
+    }
+}
+
+/// Escapes every `&` of `text` as `&&`, so it is shown as is.
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&&")
+}
+
+/// Returns `text` with a mnemonic on one of its letters or digits that isn't `taken`,
+/// preferring the first character of words, or `None` if `text` already has a mnemonic
+/// or all of its characters are taken.
+///
+/// `taken` is compared case-insensitively.
+pub fn assign(text: &str, taken: &[char]) -> Option<String> {
+    let parsed = parse(text);
+    if parsed.mnemonic.is_some() {
+        return None;
+    }
+
+    let chars = parsed.text.chars().collect::<Vec<_>>();
+    let is_free = |c: char| c.is_alphanumeric() && !taken.iter().any(|t| same_mnemonic(*t, c));
+    let is_word_start = |i: usize| i == 0 || !chars[i - 1].is_alphanumeric();
+    let index = (0..chars.len())
+        .find(|i| is_word_start(*i) && is_free(chars[*i]))
+        .or_else(|| (0..chars.len()).find(|i| is_free(chars[*i])))?;
+
+    let mut out = String::with_capacity(text.len() + 1);
+    for (i, c) in chars.into_iter().enumerate() {
+        if i == index {
+            out.push('&');
+        }
+        out.push(c);
+        if c == '&' {
+            out.push('&');
+        }
+    }
+    Some(out)
+}
+
+/// Returns whether `a` and `b` are the same mnemonic, ignoring case.
+pub fn same_mnemonic(a: char, b: char) -> bool {
+    a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Assigns a unique mnemonic to the items of `items` and their submenus that don't have one,
+/// see [`Submenu::auto_assign_mnemonics`](crate::Submenu::auto_assign_mnemonics).
+pub(crate) fn auto_assign(items: &[MenuItemKind]) {
+    let mut taken = items
+        .iter()
+        .filter_map(|i| mnemonic(&i.child().text()))
+        .collect::<Vec<_>>();
+
+    for item in items {
+        let text = item.child().text();
+        let assigned = match item {
+            MenuItemKind::Predefined(_) => None,
+            _ => assign(&text, &taken),
+        };
+
+        if let Some(text) = assigned {
+            taken.extend(mnemonic(&text));
+            match item {
+                MenuItemKind::MenuItem(i) => i.set_text(text),
+                MenuItemKind::Submenu(i) => i.set_text(text),
+                MenuItemKind::Check(i) => i.set_text(text),
+                MenuItemKind::Icon(i) => i.set_text(text),
+                MenuItemKind::Predefined(_) => {}
+            }
+        }
+
+        if let MenuItemKind::Submenu(submenu) = item {
+            auto_assign(&submenu.items());
+        }
+    }
+}
+
+/// Converts `text` to the GTK syntax, where mnemonics are marked with `_`,
+/// escaped as `__`, and `&` has no special meaning.
+#[cfg(target_os = "linux")]
+pub(crate) fn to_gtk(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '&' => match chars.next() {
+                Some('&') | None => out.push('&'),
+                // GTK can't mark `_` as a mnemonic
+                Some('_') => out.push_str("__"),
+                Some(c) => {
+                    out.push('_');
+                    out.push(c);
+                }
+            },
+            '_' => out.push_str("__"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Converts `text` from the GTK syntax, see [`to_gtk`].
+#[cfg(target_os = "linux")]
+pub(crate) fn from_gtk(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '_' => match chars.next() {
+                Some('_') | None => out.push('_'),
+                // `&` can't be marked as a mnemonic
+                Some('&') => out.push_str("&&"),
+                Some(c) => {
+                    out.push('&');
+                    out.push(c);
+                }
+            },
+            '&' => out.push_str("&&"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{assign, mnemonic, parse, strip, ParsedText};
+    use crate::{MenuItem, PredefinedMenuItem, Submenu};
+
+    #[test]
+    fn parse_mnemonic() {
+        assert_eq!(mnemonic("&File"), Some('F'));
+        assert_eq!(mnemonic("Save &As"), Some('A'));
+        assert_eq!(mnemonic("Tom && &Jerry"), Some('J'));
+        assert_eq!(mnemonic("Tom && Jerry"), None);
+
+        assert_eq!(
+            parse("コピー(&C)"),
+            ParsedText {
+                text: "コピー(C)".to_string(),
+                mnemonic: Some('C'),
+                index: Some(4),
+            }
+        );
+        assert_eq!(strip("Tom && &Jerry &"), "Tom & Jerry &");
+    }
+
+    #[test]
+    fn assign_mnemonics() {
+        assert_eq!(assign("&Open", &[]), None);
+        assert_eq!(assign("Save As", &['s']).as_deref(), Some("Save &As"));
+        assert_eq!(
+            assign("Save && Quit", &['s']).as_deref(),
+            Some("Save && &Quit")
+        );
+        assert_eq!(assign("Save", &['S']).as_deref(), Some("S&ave"));
+        assert_eq!(assign("Sa", &['s', 'A']), None);
+        assert_eq!(assign("...", &[]), None);
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn auto_assign_mnemonics() {
+        let item = |text: &str| MenuItem::new(text, true, None);
+        let (open, save, save_as, paste) =
+            (item("&Open"), item("Save"), item("Save As"), item("Paste"));
+        let recent = Submenu::with_items("Recent", true, &[&item("Older")]).unwrap();
+        let file = Submenu::with_items(
+            "&File",
+            true,
+            &[
+                &open,
+                &save,
+                &save_as,
+                &recent,
+                &PredefinedMenuItem::copy(None),
+                &paste,
+            ],
+        )
+        .unwrap();
+
+        file.auto_assign_mnemonics();
+        assert_eq!(file.text(), "&File");
+        assert_eq!(open.text(), "&Open");
+        assert_eq!(save.text(), "&Save");
+        assert_eq!(save_as.text(), "Save &As");
+        assert_eq!(recent.text(), "&Recent");
+        assert_eq!(recent.items()[0].as_menuitem_unchecked().text(), "&Older");
+        assert_eq!(paste.text(), "&Paste");
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn display_text() {
+        let item = MenuItem::new("Tom && &Jerry", true, None);
+        let select_all = PredefinedMenuItem::select_all(Some("Select &All"));
+        let submenu = Submenu::new("Edit", true);
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            assert_eq!(item.text(), "Tom && &Jerry");
+            assert_eq!(item.mnemonic(), Some('J'));
+            assert_eq!(select_all.mnemonic(), Some('A'));
+        }
+        #[cfg(target_os = "macos")]
+        {
+            assert_eq!(item.text(), "Tom & Jerry");
+            assert_eq!(item.mnemonic(), None);
+            assert_eq!(select_all.mnemonic(), None);
+        }
+        assert_eq!(item.display_text(), "Tom & Jerry");
+        assert_eq!(select_all.display_text(), "Select All");
+        assert_eq!(submenu.display_text(), "Edit");
+        assert_eq!(submenu.mnemonic(), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn gtk_mnemonics() {
+        use super::{from_gtk, to_gtk};
+
+        for (text, gtk) in [
+            ("&File", "_File"),
+            ("Tom && &Jerry", "Tom & _Jerry"),
+            ("snake_case", "snake__case"),
+        ] {
+            assert_eq!(to_gtk(text), gtk);
+            assert_eq!(from_gtk(gtk), text);
+        }
+    }
+}
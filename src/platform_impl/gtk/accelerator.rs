@@ -7,23 +7,6 @@ use keyboard_types::{Code, Modifiers};
 
 use crate::accelerator::{Accelerator, AcceleratorParseError};
 
-pub fn to_gtk_mnemonic<S: AsRef<str>>(string: S) -> String {
-    string
-        .as_ref()
-        .replace("&&", "[~~]")
-        .replace('&', "_")
-        .replace("[~~]", "&&")
-        .replace("[~~]", "&")
-}
-
-pub fn from_gtk_mnemonic<S: AsRef<str>>(string: S) -> String {
-    string
-        .as_ref()
-        .replace("__", "[~~]")
-        .replace('_', "&")
-        .replace("[~~]", "__")
-}
-
 pub fn parse_accelerator(
     accelerator: &Accelerator,
 ) -> Result<(gdk::ModifierType, u32), AcceleratorParseError> {
@@ -175,8 +158,113 @@ fn key_to_raw_key(src: &Code) -> Option<gdk::keys::Key> {
         Code::ArrowLeft => Left,
         Code::ArrowRight => Right,
 
+        Code::F25 => F25,
+        Code::F26 => F26,
+        Code::F27 => F27,
+        Code::F28 => F28,
+        Code::F29 => F29,
+        Code::F30 => F30,
+        Code::F31 => F31,
+        Code::F32 => F32,
+        Code::F33 => F33,
+        Code::F34 => F34,
+        Code::F35 => F35,
+
+        Code::IntlBackslash => less,
+        Code::IntlRo => backslash,
+        Code::IntlYen => yen,
+
+        Code::Numpad0 => KP_0,
+        Code::Numpad1 => KP_1,
+        Code::Numpad2 => KP_2,
+        Code::Numpad3 => KP_3,
+        Code::Numpad4 => KP_4,
+        Code::Numpad5 => KP_5,
+        Code::Numpad6 => KP_6,
+        Code::Numpad7 => KP_7,
+        Code::Numpad8 => KP_8,
+        Code::Numpad9 => KP_9,
+        Code::NumpadAdd => KP_Add,
+        Code::NumpadSubtract => KP_Subtract,
+        Code::NumpadMultiply | Code::NumpadStar => KP_Multiply,
+        Code::NumpadDivide => KP_Divide,
+        Code::NumpadDecimal => KP_Decimal,
+        Code::NumpadComma => KP_Separator,
+        Code::NumpadEnter => KP_Enter,
+        Code::NumpadEqual => KP_Equal,
+        Code::NumpadParenLeft => parenleft,
+        Code::NumpadParenRight => parenright,
+        Code::NumpadHash => numbersign,
+        Code::NumpadBackspace => BackSpace,
+        Code::NumpadClear | Code::NumpadClearEntry => Clear,
+
+        Code::Convert => Henkan,
+        Code::NonConvert => Muhenkan,
+        Code::KanaMode => Hiragana_Katakana,
+        Code::Lang1 => Hangul,
+        Code::Lang2 => Hangul_Hanja,
+        Code::Lang3 => Katakana,
+        Code::Lang4 => Hiragana,
+        Code::Lang5 => Zenkaku_Hankaku,
+        Code::Hiragana => Hiragana,
+        Code::Katakana => Katakana,
+
         Code::ContextMenu => Menu,
+        Code::Help => Help,
+        Code::Hyper => Hyper_L,
+        Code::Super => Super_L,
+
+        Code::BrowserBack => Back,
+        Code::BrowserFavorites => Favorites,
+        Code::BrowserForward => Forward,
+        Code::BrowserHome => HomePage,
+        Code::BrowserRefresh => Refresh,
+        Code::BrowserSearch => Search,
+        Code::BrowserStop => Stop,
+
+        Code::AudioVolumeDown => AudioLowerVolume,
+        Code::AudioVolumeMute => AudioMute,
+        Code::AudioVolumeUp => AudioRaiseVolume,
+        Code::MediaPlayPause | Code::MediaPlay => AudioPlay,
+        Code::MediaPause => AudioPause,
+        Code::MediaStop => AudioStop,
+        Code::MediaTrackNext => AudioNext,
+        Code::MediaTrackPrevious => AudioPrev,
+        Code::MediaRecord => AudioRecord,
+        Code::MediaRewind => AudioRewind,
+        Code::MediaFastForward => AudioForward,
+        Code::MediaSelect => AudioMedia,
+        Code::MicrophoneMuteToggle => AudioMicMute,
+
+        Code::LaunchApp1 => MyComputer,
+        Code::LaunchApp2 => Calculator,
+        Code::LaunchMail => Mail,
+        Code::LaunchScreenSaver => ScreenSaver,
+        Code::MailForward => MailForward,
+        Code::MailReply => Reply,
+        Code::MailSend => Send,
+
+        Code::Eject => Eject,
+        Code::Power => PowerOff,
+        Code::Sleep => Sleep,
         Code::WakeUp => WakeUp,
+        Code::Suspend => Suspend,
+        Code::BrightnessDown => MonBrightnessDown,
+        Code::BrightnessUp => MonBrightnessUp,
+        Code::DisplayToggleIntExt => Display,
+
+        Code::Abort => Cancel,
+        Code::Again => Redo,
+        Code::Copy => Copy,
+        Code::Cut => Cut,
+        Code::Find => Find,
+        Code::Open => Open,
+        Code::Paste => Paste,
+        Code::Select => Select,
+        Code::Undo => Undo,
+
+        // No keysym exists for `Fn`, `FnLock`, `Turbo`, `Props` and a few
+        // vendor specific keys.
         _ => return None,
     })
 }
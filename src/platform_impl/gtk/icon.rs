@@ -7,7 +7,7 @@ use gtk::gdk_pixbuf::{Colorspace, Pixbuf};
 use crate::icon::BadIcon;
 
 /// An icon used for the window titlebar, taskbar, etc.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PlatformIcon {
     raw: Vec<u8>,
     width: i32,
@@ -10,13 +10,15 @@ pub(crate) use icon::PlatformIcon;
 use crate::{
     accelerator::Accelerator,
     dpi::Position,
+    group::MenuGroups,
     icon::{Icon, NativeIcon},
     items::*,
-    util::{AddOp, Counter},
+    mnemonic,
+    util::{defer, is_batching, move_element, AddOp, Counter},
     IsMenuItem, MenuEvent, MenuId, MenuItemKind, MenuItemType,
 };
-use accelerator::{from_gtk_mnemonic, parse_accelerator, to_gtk_mnemonic};
-use glib::translate::ToGlibPtr;
+use accelerator::parse_accelerator;
+use glib::translate::{IntoGlib, ToGlibPtr, ToGlibPtrMut};
 use gtk::{gdk, glib, prelude::*, AboutDialog, Container, Orientation};
 use std::{
     cell::RefCell,
@@ -31,19 +33,11 @@ macro_rules! is_item_supported {
     ($item:tt) => {{
         let child = $item.child();
         let child_ = child.borrow();
-        let supported = if let Some(predefined_item_type) = &child_.predefined_item_type {
-            matches!(
-                predefined_item_type,
-                PredefinedMenuItemType::Separator
-                    | PredefinedMenuItemType::Copy
-                    | PredefinedMenuItemType::Cut
-                    | PredefinedMenuItemType::Paste
-                    | PredefinedMenuItemType::SelectAll
-                    | PredefinedMenuItemType::About(_)
-            )
-        } else {
-            true
-        };
+        let supported = child_
+            .predefined_item_type
+            .as_ref()
+            .map(|t| t.is_supported())
+            .unwrap_or(true);
         drop(child_);
         supported
     }};
@@ -60,6 +54,7 @@ macro_rules! return_if_item_not_supported {
 pub struct Menu {
     id: MenuId,
     children: Vec<Rc<RefCell<MenuChild>>>,
+    groups: MenuGroups,
     // TODO: maybe save a reference to the window?
     gtk_menubars: HashMap<u32, gtk::MenuBar>,
     accel_group: Option<gtk::AccelGroup>,
@@ -85,6 +80,7 @@ impl Menu {
         Self {
             id: id.unwrap_or_else(|| MenuId(COUNTER.next().to_string())),
             children: Vec::new(),
+            groups: MenuGroups::default(),
             gtk_menubars: HashMap::new(),
             accel_group: None,
             gtk_menu: (COUNTER.next(), None),
@@ -95,8 +91,13 @@ impl Menu {
         &self.id
     }
 
+    pub fn groups_mut(&mut self) -> &mut MenuGroups {
+        &mut self.groups
+    }
+
     pub fn add_menu_item(&mut self, item: &dyn crate::IsMenuItem, op: AddOp) -> crate::Result<()> {
-        if is_item_supported!(item) {
+        // during a batch, the gtk items are created when it ends, see `defer_gtk_items`
+        if is_item_supported!(item) && !is_batching() {
             for (menu_id, menu_bar) in &self.gtk_menubars {
                 let gtk_item =
                     item.make_gtk_menu_item(*menu_id, self.accel_group.as_ref(), true, true)?;
@@ -200,7 +201,7 @@ impl Menu {
                                 for item in child_.items() {
                                     child_.remove_inner(item.as_ref(), false, Some(id))?;
                                 }
-                                unsafe { menu.destroy() };
+                                destroy_gtk_widget(menu);
                             }
                         }
                         child_.gtk_menus.as_mut().unwrap().remove(menu_id);
@@ -215,7 +216,7 @@ impl Menu {
                                     item.remove_accelerator(accel_group, key, mods);
                                 }
                             }
-                            unsafe { item.destroy() };
+                            destroy_gtk_widget(item);
                         }
                     };
                 }
@@ -234,7 +235,7 @@ impl Menu {
                                 item.remove_accelerator(accel_group, key, mods);
                             }
                         }
-                        unsafe { item.destroy() };
+                        destroy_gtk_widget(item);
                     }
                 };
             }
@@ -242,6 +243,40 @@ impl Menu {
         Ok(())
     }
 
+    pub fn move_item(&mut self, from: usize, to: usize) -> crate::Result<()> {
+        move_element(&mut self.children, from, to)?;
+
+        for (menu_id, menu_bar) in &self.gtk_menubars {
+            reorder_gtk_menu_items(menu_bar.upcast_ref(), *menu_id, &self.children);
+        }
+
+        if let (menu_id, Some(menu)) = &self.gtk_menu {
+            reorder_gtk_menu_items(menu.upcast_ref(), *menu_id, &self.children);
+        }
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) -> crate::Result<()> {
+        for (menu_id, menu_bar) in &self.gtk_menubars {
+            drop_children_from_menu_and_destroy(*menu_id, menu_bar, &self.children);
+        }
+
+        if let (menu_id, Some(menu)) = &self.gtk_menu {
+            drop_children_from_menu_and_destroy(*menu_id, menu, &self.children);
+        }
+
+        self.children.clear();
+
+        Ok(())
+    }
+
+    pub fn item_at(&self, position: usize) -> Option<MenuItemKind> {
+        self.children
+            .get(position)
+            .map(|c| c.borrow().kind(c.clone()))
+    }
+
     pub fn items(&self) -> Vec<MenuItemKind> {
         self.children
             .iter()
@@ -398,6 +433,7 @@ pub struct MenuChild {
     // shared fields between submenus and menu items
     item_type: MenuItemType,
     text: String,
+    text_key: Option<String>,
     enabled: bool,
     id: MenuId,
 
@@ -409,6 +445,7 @@ pub struct MenuChild {
 
     // predefined menu item fields
     predefined_item_type: Option<PredefinedMenuItemType>,
+    action_handler: Rc<RefCell<Option<ActionHandler>>>,
 
     // check menu item fields
     checked: Option<Rc<AtomicBool>>,
@@ -419,6 +456,7 @@ pub struct MenuChild {
 
     // submenu fields
     pub children: Option<Vec<Rc<RefCell<MenuChild>>>>,
+    pub groups: Option<MenuGroups>,
     gtk_menus: Option<HashMap<u32, Vec<(u32, gtk::Menu)>>>,
     gtk_menu: Option<(u32, Option<gtk::Menu>)>, // dedicated menu for tray or context menus
     accel_group: Option<gtk::AccelGroup>,
@@ -453,6 +491,174 @@ impl Drop for MenuChild {
     }
 }
 
+/// Reorders the gtk items that belong to `menu_id` in `shell` to match the order of `children`,
+/// without destroying them.
+fn reorder_gtk_menu_items(
+    shell: &gtk::MenuShell,
+    menu_id: u32,
+    children: &[Rc<RefCell<MenuChild>>],
+) {
+    let mut position = 0;
+    for child in children {
+        let child = child.borrow();
+        let gtk_menu_items = child.gtk_menu_items.borrow();
+        if let Some(items) = gtk_menu_items.get(&menu_id) {
+            for item in items {
+                match shell.downcast_ref::<gtk::Menu>() {
+                    Some(menu) => menu.reorder_child(item, position),
+                    // `gtk::MenuBar` can't reorder its children, but removing an item
+                    // doesn't destroy it as long as we hold a reference to it.
+                    None => {
+                        shell.remove(item);
+                        shell.insert(item, position);
+                    }
+                }
+                position += 1;
+            }
+        }
+    }
+}
+
+/// Creates the gtk items of `children` that `shell` doesn't have yet, like the items
+/// added during a batch, and inserts them at their position.
+fn add_missing_gtk_items(
+    shell: &gtk::MenuShell,
+    menu_id: u32,
+    children: &[Rc<RefCell<MenuChild>>],
+    accel_group: Option<&gtk::AccelGroup>,
+    for_menu_bar: bool,
+) -> crate::Result<()> {
+    // an item can be added more than once, its nth appearance needs its nth gtk item
+    let mut seen = HashMap::new();
+    let mut position = 0;
+    for child in children {
+        let nth = seen.entry(Rc::as_ptr(child)).or_insert(0);
+        let (kind, supported, count) = {
+            let child_ = child.borrow();
+            let supported = child_
+                .predefined_item_type
+                .as_ref()
+                .map(|t| t.is_supported())
+                .unwrap_or(true);
+            let count = child_
+                .gtk_menu_items
+                .borrow()
+                .get(&menu_id)
+                .map(Vec::len)
+                .unwrap_or(0);
+            (child_.kind(child.clone()), supported, count)
+        };
+        if !supported {
+            continue;
+        }
+
+        if count <= *nth {
+            let gtk_item = kind.make_gtk_menu_item(menu_id, accel_group, true, for_menu_bar)?;
+            shell.insert(&gtk_item, position);
+            gtk_item.show();
+        }
+        *nth += 1;
+        position += 1;
+    }
+
+    Ok(())
+}
+
+/// A menu or a submenu whose gtk items can be created at the end of a batch, see [`defer_gtk_items`].
+pub(crate) trait DeferGtkItems {
+    /// Creates the gtk items of the children that don't have one yet,
+    /// for every menubar and context menu.
+    fn add_missing_gtk_items(&self) -> crate::Result<()>;
+}
+
+impl DeferGtkItems for Menu {
+    fn add_missing_gtk_items(&self) -> crate::Result<()> {
+        for (menu_id, menu_bar) in &self.gtk_menubars {
+            add_missing_gtk_items(
+                menu_bar.upcast_ref(),
+                *menu_id,
+                &self.children,
+                self.accel_group.as_ref(),
+                true,
+            )?;
+        }
+
+        if let (menu_id, Some(menu)) = &self.gtk_menu {
+            add_missing_gtk_items(
+                menu.upcast_ref(),
+                *menu_id,
+                &self.children,
+                self.accel_group.as_ref(),
+                false,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DeferGtkItems for MenuChild {
+    fn add_missing_gtk_items(&self) -> crate::Result<()> {
+        let children = match self.children.as_ref() {
+            Some(children) => children,
+            None => return Ok(()),
+        };
+
+        for menus in self.gtk_menus.as_ref().unwrap().values() {
+            for (menu_id, menu) in menus {
+                add_missing_gtk_items(
+                    menu.upcast_ref(),
+                    *menu_id,
+                    children,
+                    self.accel_group.as_ref(),
+                    false,
+                )?;
+            }
+        }
+
+        if let (menu_id, Some(menu)) = self.gtk_menu.as_ref().unwrap() {
+            add_missing_gtk_items(
+                menu.upcast_ref(),
+                *menu_id,
+                children,
+                self.accel_group.as_ref(),
+                false,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates the gtk items of the children added to `container` during a batch
+/// in one pass when it ends, instead of one item at a time.
+///
+/// Errors can't be returned at the end of a batch, items whose gtk item can't be created are skipped.
+pub(crate) fn defer_gtk_items<T: DeferGtkItems + 'static>(container: &Rc<RefCell<T>>) {
+    if !is_batching() {
+        return;
+    }
+
+    let weak = Rc::downgrade(container);
+    defer((Rc::as_ptr(container) as usize, "items"), move || {
+        if let Some(container) = weak.upgrade() {
+            let _ = container.borrow().add_missing_gtk_items();
+        }
+    });
+}
+
+/// Destroys a gtk item or menu that was removed from its menu, or if called
+/// inside a batch, destroys it when the batch ends.
+///
+/// The widget must already be detached from its shell so the positions
+/// of the remaining items stay correct while the batch runs.
+fn destroy_gtk_widget(widget: impl IsA<gtk::Widget>) {
+    let widget = widget.upcast::<gtk::Widget>();
+    defer((widget.as_ptr() as usize, "destroy"), move || unsafe {
+        widget.destroy()
+    });
+}
+
 fn drop_children_from_menu_and_destroy(
     id: u32,
     menu: &impl IsA<Container>,
@@ -505,6 +711,9 @@ impl MenuChild {
             accel_group: None,
             checked: None,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: Rc::new(RefCell::new(None)),
             gtk_accelerator: None,
             gtk_menu: None,
             gtk_menus: None,
@@ -520,6 +729,9 @@ impl MenuChild {
             enabled,
             id: id.unwrap_or_else(|| MenuId(COUNTER.next().to_string())),
             children: Some(Vec::new()),
+            groups: Some(MenuGroups::default()),
+            text_key: None,
+            action_handler: Rc::new(RefCell::new(None)),
             item_type: MenuItemType::Submenu,
             gtk_menu: Some((COUNTER.next(), None)),
             gtk_menu_items: Rc::new(RefCell::new(HashMap::new())),
@@ -534,11 +746,15 @@ impl MenuChild {
         }
     }
 
-    pub(crate) fn new_predefined(item_type: PredefinedMenuItemType, text: Option<String>) -> Self {
+    pub(crate) fn new_predefined(
+        item_type: PredefinedMenuItemType,
+        text: Option<String>,
+        accelerator: Option<Accelerator>,
+    ) -> Self {
         Self {
             text: text.unwrap_or_else(|| item_type.text().to_string()),
             enabled: true,
-            accelerator: item_type.accelerator(),
+            accelerator,
             id: MenuId(COUNTER.next().to_string()),
             item_type: MenuItemType::Predefined,
             predefined_item_type: Some(item_type),
@@ -546,6 +762,9 @@ impl MenuChild {
             accel_group: None,
             checked: None,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: Rc::new(RefCell::new(None)),
             gtk_accelerator: None,
             gtk_menu: None,
             gtk_menus: None,
@@ -572,6 +791,9 @@ impl MenuChild {
             gtk_menu_items: Rc::new(RefCell::new(HashMap::new())),
             accel_group: None,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: Rc::new(RefCell::new(None)),
             gtk_accelerator: None,
             gtk_menu: None,
             gtk_menus: None,
@@ -598,6 +820,9 @@ impl MenuChild {
             accel_group: None,
             checked: None,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: Rc::new(RefCell::new(None)),
             gtk_accelerator: None,
             gtk_menu: None,
             gtk_menus: None,
@@ -623,6 +848,9 @@ impl MenuChild {
             accel_group: None,
             checked: None,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: Rc::new(RefCell::new(None)),
             gtk_accelerator: None,
             gtk_menu: None,
             gtk_menus: None,
@@ -643,7 +871,31 @@ impl MenuChild {
         &self.id
     }
 
+    pub fn accelerator(&self) -> Option<Accelerator> {
+        self.accelerator
+    }
+
+    pub fn predefined_item_type(&self) -> Option<PredefinedMenuItemType> {
+        self.predefined_item_type.clone()
+    }
+
+    pub fn icon(&self) -> Option<Icon> {
+        self.icon.clone()
+    }
+
+    pub fn text_key(&self) -> Option<String> {
+        self.text_key.clone()
+    }
+
+    pub fn set_text_key(&mut self, key: Option<String>) {
+        self.text_key = key;
+    }
+
     pub fn text(&self) -> String {
+        if is_batching() {
+            return self.text.clone();
+        }
+
         match self
             .gtk_menu_items
             .borrow()
@@ -651,7 +903,7 @@ impl MenuChild {
             .collect::<Vec<_>>()
             .first()
             .map(|v| v.first())
-            .map(|e| e.map(|i| i.label().map(from_gtk_mnemonic)))
+            .map(|e| e.map(|i| i.label().map(|l| mnemonic::from_gtk(&l))))
         {
             Some(Some(Some(text))) => text,
             _ => self.text.clone(),
@@ -660,15 +912,22 @@ impl MenuChild {
 
     pub fn set_text(&mut self, text: &str) {
         self.text = text.to_string();
-        let text = to_gtk_mnemonic(text);
-        for items in self.gtk_menu_items.borrow().values() {
-            for i in items {
-                i.set_label(&text);
+        let text = mnemonic::to_gtk(text);
+        let gtk_menu_items = self.gtk_menu_items.clone();
+        defer((Rc::as_ptr(&gtk_menu_items) as usize, "text"), move || {
+            for items in gtk_menu_items.borrow().values() {
+                for i in items {
+                    i.set_label(&text);
+                }
             }
-        }
+        });
     }
 
     pub fn is_enabled(&self) -> bool {
+        if is_batching() {
+            return self.enabled;
+        }
+
         match self
             .gtk_menu_items
             .borrow()
@@ -685,25 +944,39 @@ impl MenuChild {
 
     pub fn set_enabled(&mut self, enabled: bool) {
         self.enabled = enabled;
-        for items in self.gtk_menu_items.borrow().values() {
-            for i in items {
-                i.set_sensitive(enabled);
-            }
-        }
+        let gtk_menu_items = self.gtk_menu_items.clone();
+        defer(
+            (Rc::as_ptr(&gtk_menu_items) as usize, "enabled"),
+            move || {
+                for items in gtk_menu_items.borrow().values() {
+                    for i in items {
+                        i.set_sensitive(enabled);
+                    }
+                }
+            },
+        );
     }
 
     pub fn set_accelerator(&mut self, accelerator: Option<Accelerator>) -> crate::Result<()> {
-        let prev_accel = self.gtk_accelerator.as_ref();
+        let prev_accel = self.gtk_accelerator;
+        let prev_shown_only = self.is_accelerator_shown_only();
         let new_accel = accelerator.as_ref().map(parse_accelerator).transpose()?;
 
+        self.gtk_accelerator = new_accel;
+        self.accelerator = accelerator;
+        let shown_only = self.is_accelerator_shown_only();
+
         for items in self.gtk_menu_items.borrow().values() {
             for i in items {
-                if let Some((mods, key)) = prev_accel {
+                if prev_shown_only || shown_only {
+                    show_accelerator(i, new_accel.filter(|_| shown_only));
+                }
+                if let Some((mods, key)) = prev_accel.filter(|_| !prev_shown_only) {
                     if let Some(accel_group) = &self.accel_group {
-                        i.remove_accelerator(accel_group, *key, *mods);
+                        i.remove_accelerator(accel_group, key, mods);
                     }
                 }
-                if let Some((mods, key)) = new_accel {
+                if let Some((mods, key)) = new_accel.filter(|_| !shown_only) {
                     if let Some(accel_group) = &self.accel_group {
                         i.add_accelerator(
                             "activate",
@@ -717,16 +990,42 @@ impl MenuChild {
             }
         }
 
-        self.gtk_accelerator = new_accel;
-        self.accelerator = accelerator;
-
         Ok(())
     }
+
+    /// Whether the accelerator is only shown and not registered, which is the case
+    /// for clipboard items with their default accelerator, so it still reaches the focused widget.
+    fn is_accelerator_shown_only(&self) -> bool {
+        match &self.predefined_item_type {
+            Some(
+                item_type @ (PredefinedMenuItemType::Copy
+                | PredefinedMenuItemType::Cut
+                | PredefinedMenuItemType::Paste
+                | PredefinedMenuItemType::SelectAll),
+            ) => self.accelerator.is_some() && self.accelerator == item_type.accelerator(),
+            _ => false,
+        }
+    }
+}
+
+/// PredefinedMenuItem methods
+impl MenuChild {
+    pub fn action_handler(&self) -> Option<ActionHandler> {
+        self.action_handler.borrow().clone()
+    }
+
+    pub fn set_action_handler(&mut self, handler: Option<ActionHandler>) {
+        *self.action_handler.borrow_mut() = handler;
+    }
 }
 
 /// CheckMenuItem methods
 impl MenuChild {
     pub fn is_checked(&self) -> bool {
+        if is_batching() {
+            return self.checked.as_ref().unwrap().load(Ordering::Relaxed);
+        }
+
         match self
             .gtk_menu_items
             .borrow()
@@ -746,16 +1045,22 @@ impl MenuChild {
             .as_ref()
             .unwrap()
             .store(checked, Ordering::Release);
-        let is_syncing = self.is_syncing_checked_state.as_ref().unwrap();
-        is_syncing.store(true, Ordering::Release);
-        for items in self.gtk_menu_items.borrow().values() {
-            for i in items {
-                i.downcast_ref::<gtk::CheckMenuItem>()
-                    .unwrap()
-                    .set_active(checked);
-            }
-        }
-        is_syncing.store(false, Ordering::Release);
+        let is_syncing = self.is_syncing_checked_state.clone().unwrap();
+        let gtk_menu_items = self.gtk_menu_items.clone();
+        defer(
+            (Rc::as_ptr(&gtk_menu_items) as usize, "checked"),
+            move || {
+                is_syncing.store(true, Ordering::Release);
+                for items in gtk_menu_items.borrow().values() {
+                    for i in items {
+                        i.downcast_ref::<gtk::CheckMenuItem>()
+                            .unwrap()
+                            .set_active(checked);
+                    }
+                }
+                is_syncing.store(false, Ordering::Release);
+            },
+        );
     }
 }
 
@@ -780,7 +1085,8 @@ impl MenuChild {
 /// Submenu methods
 impl MenuChild {
     pub fn add_menu_item(&mut self, item: &dyn crate::IsMenuItem, op: AddOp) -> crate::Result<()> {
-        if is_item_supported!(item) {
+        // during a batch, the gtk items are created when it ends, see `defer_gtk_items`
+        if is_item_supported!(item) && !is_batching() {
             for menus in self.gtk_menus.as_ref().unwrap().values() {
                 for (menu_id, menu) in menus {
                     let gtk_item =
@@ -895,7 +1201,7 @@ impl MenuChild {
                                     for item in child_.items() {
                                         child_.remove_inner(item.as_ref(), false, Some(id))?;
                                     }
-                                    unsafe { menu.destroy() };
+                                    destroy_gtk_widget(menu);
                                 }
                             }
                             child_.gtk_menus.as_mut().unwrap().remove(menu_id);
@@ -910,7 +1216,7 @@ impl MenuChild {
                                         item.remove_accelerator(accel_group, key, mods);
                                     }
                                 }
-                                unsafe { item.destroy() };
+                                destroy_gtk_widget(item);
                             }
                         };
                     }
@@ -930,7 +1236,7 @@ impl MenuChild {
                                 item.remove_accelerator(accel_group, key, mods);
                             }
                         }
-                        unsafe { item.destroy() };
+                        destroy_gtk_widget(item);
                     }
                 };
             }
@@ -939,6 +1245,49 @@ impl MenuChild {
         Ok(())
     }
 
+    pub fn move_item(&mut self, from: usize, to: usize) -> crate::Result<()> {
+        move_element(self.children.as_mut().unwrap(), from, to)?;
+        let children = self.children.as_ref().unwrap();
+
+        for menus in self.gtk_menus.as_ref().unwrap().values() {
+            for (menu_id, menu) in menus {
+                reorder_gtk_menu_items(menu.upcast_ref(), *menu_id, children);
+            }
+        }
+
+        if let (menu_id, Some(menu)) = self.gtk_menu.as_ref().unwrap() {
+            reorder_gtk_menu_items(menu.upcast_ref(), *menu_id, children);
+        }
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) -> crate::Result<()> {
+        let children = self.children.as_ref().unwrap();
+
+        for menus in self.gtk_menus.as_ref().unwrap().values() {
+            for (menu_id, menu) in menus {
+                drop_children_from_menu_and_destroy(*menu_id, menu, children);
+            }
+        }
+
+        if let (menu_id, Some(menu)) = self.gtk_menu.as_ref().unwrap() {
+            drop_children_from_menu_and_destroy(*menu_id, menu, children);
+        }
+
+        self.children.as_mut().unwrap().clear();
+
+        Ok(())
+    }
+
+    pub fn item_at(&self, position: usize) -> Option<MenuItemKind> {
+        self.children
+            .as_ref()
+            .unwrap()
+            .get(position)
+            .map(|c| c.borrow().kind(c.clone()))
+    }
+
     pub fn items(&self) -> Vec<MenuItemKind> {
         self.children
             .as_ref()
@@ -1008,7 +1357,7 @@ impl MenuChild {
     ) -> crate::Result<gtk::MenuItem> {
         let submenu = gtk::Menu::new();
         let item = gtk::MenuItem::builder()
-            .label(to_gtk_mnemonic(&self.text))
+            .label(mnemonic::to_gtk(&self.text))
             .use_underline(true)
             .submenu(&submenu)
             .sensitive(self.enabled)
@@ -1055,7 +1404,7 @@ impl MenuChild {
         add_to_cache: bool,
     ) -> crate::Result<gtk::MenuItem> {
         let item = gtk::MenuItem::builder()
-            .label(to_gtk_mnemonic(&self.text))
+            .label(mnemonic::to_gtk(&self.text))
             .use_underline(true)
             .sensitive(self.enabled)
             .build();
@@ -1094,12 +1443,29 @@ impl MenuChild {
             .transpose()?;
         let predefined_item_type = self.predefined_item_type.clone().unwrap();
 
+        self.accel_group = accel_group.cloned();
+
+        let id = self.id.clone();
+        let action_handler = self.action_handler.clone();
+        let item_type = predefined_item_type.clone();
         let make_item = || {
-            gtk::MenuItem::builder()
-                .label(to_gtk_mnemonic(&text))
+            let item = gtk::MenuItem::builder()
+                .label(mnemonic::to_gtk(&text))
                 .use_underline(true)
                 .sensitive(true)
-                .build()
+                .build();
+
+            // connected before the native action so it can stop it from running
+            let id = id.clone();
+            let action_handler = action_handler.clone();
+            let item_type = item_type.clone();
+            item.connect_activate(move |item| {
+                if run_action_handler(&action_handler, &item_type, &id) {
+                    item.stop_signal_emission_by_name("activate");
+                }
+            });
+
+            item
         };
         let register_accel = |item: &gtk::MenuItem| {
             if let Some((mods, key)) = &self.gtk_accelerator {
@@ -1122,20 +1488,18 @@ impl MenuChild {
             PredefinedMenuItemType::Copy
             | PredefinedMenuItemType::Cut
             | PredefinedMenuItemType::Paste
-            | PredefinedMenuItemType::SelectAll => {
+            | PredefinedMenuItemType::SelectAll
+            | PredefinedMenuItemType::Delete => {
                 let item = make_item();
-                let (mods, key) =
-                    parse_accelerator(&predefined_item_type.accelerator().unwrap()).unwrap();
-                item.child()
-                    .unwrap()
-                    .downcast::<gtk::AccelLabel>()
-                    .unwrap()
-                    .set_accel(key, mods);
-                item.connect_activate(move |_| {
-                    // TODO: wayland
-                    #[cfg(feature = "libxdo")]
-                    if let Ok(xdo) = libxdo::XDo::new(None) {
-                        let _ = xdo.send_keysequence(predefined_item_type.xdo_keys(), 0);
+                if self.is_accelerator_shown_only() {
+                    show_accelerator(&item, self.gtk_accelerator);
+                } else {
+                    register_accel(&item);
+                }
+                item.connect_activate(move |item| {
+                    match window_for_gtk_menu_item(item).and_then(|w| w.focused_widget()) {
+                        Some(widget) => execute_edit_command(&widget, &predefined_item_type),
+                        None => send_edit_shortcut(None, &predefined_item_type),
                     }
                 });
                 item
@@ -1184,6 +1548,74 @@ impl MenuChild {
                 });
                 item
             }
+            PredefinedMenuItemType::Minimize
+            | PredefinedMenuItemType::Maximize
+            | PredefinedMenuItemType::Fullscreen
+            | PredefinedMenuItemType::Hide
+            | PredefinedMenuItemType::CloseWindow
+            | PredefinedMenuItemType::Quit => {
+                let item = make_item();
+                register_accel(&item);
+                let id = self.id.clone();
+                item.connect_activate(move |item| {
+                    if let PredefinedMenuItemType::Quit = predefined_item_type {
+                        // `gtk::main_quit` can only be called from inside `gtk::main`,
+                        // apps running their own event loop, like tao, quit on the event
+                        if gtk::main_level() > 0 {
+                            gtk::main_quit();
+                        } else {
+                            MenuEvent::send(crate::MenuEvent { id: id.clone() });
+                        }
+                        return;
+                    }
+
+                    let window = match window_for_gtk_menu_item(item) {
+                        Some(window) => window,
+                        None => return,
+                    };
+                    match predefined_item_type {
+                        PredefinedMenuItemType::Minimize => window.iconify(),
+                        PredefinedMenuItemType::Maximize => {
+                            if window.is_maximized() {
+                                window.unmaximize()
+                            } else {
+                                window.maximize()
+                            }
+                        }
+                        PredefinedMenuItemType::Fullscreen => {
+                            let is_fullscreen = window
+                                .window()
+                                .map(|w| w.state().contains(gdk::WindowState::FULLSCREEN))
+                                .unwrap_or(false);
+                            if is_fullscreen {
+                                window.unfullscreen()
+                            } else {
+                                window.fullscreen()
+                            }
+                        }
+                        PredefinedMenuItemType::Hide => window.hide(),
+                        PredefinedMenuItemType::CloseWindow => window.close(),
+                        _ => {}
+                    }
+                });
+                item
+            }
+            PredefinedMenuItemType::Find
+            | PredefinedMenuItemType::FindNext
+            | PredefinedMenuItemType::Preferences
+            | PredefinedMenuItemType::Help
+            | PredefinedMenuItemType::NewWindow
+            | PredefinedMenuItemType::ZoomIn
+            | PredefinedMenuItemType::ZoomOut
+            | PredefinedMenuItemType::ZoomReset => {
+                let item = make_item();
+                register_accel(&item);
+                let id = self.id.clone();
+                item.connect_activate(move |_| {
+                    MenuEvent::send(crate::MenuEvent { id: id.clone() });
+                });
+                item
+            }
             _ => unreachable!(),
         };
 
@@ -1204,7 +1636,7 @@ impl MenuChild {
         add_to_cache: bool,
     ) -> crate::Result<gtk::MenuItem> {
         let item = gtk::CheckMenuItem::builder()
-            .label(to_gtk_mnemonic(&self.text))
+            .label(mnemonic::to_gtk(&self.text))
             .use_underline(true)
             .sensitive(self.enabled)
             .active(self.checked.as_ref().unwrap().load(Ordering::Relaxed))
@@ -1270,7 +1702,7 @@ impl MenuChild {
         self.accel_group = accel_group.cloned();
 
         let label = gtk::AccelLabel::builder()
-            .label(to_gtk_mnemonic(&self.text))
+            .label(mnemonic::to_gtk(&self.text))
             .use_underline(true)
             .xalign(0.0)
             .build();
@@ -1360,6 +1792,235 @@ impl dyn IsMenuItem + '_ {
     }
 }
 
+/// Shows `accelerator` next to the text of `item` without registering it.
+fn show_accelerator(item: &gtk::MenuItem, accelerator: Option<(gdk::ModifierType, u32)>) {
+    if let Some(label) = item
+        .child()
+        .and_then(|c| c.downcast::<gtk::AccelLabel>().ok())
+    {
+        let (mods, key) = accelerator.unwrap_or((gdk::ModifierType::empty(), 0));
+        label.set_accel(key, mods);
+    }
+}
+
+fn run_action_handler(
+    action_handler: &RefCell<Option<ActionHandler>>,
+    item_type: &PredefinedMenuItemType,
+    id: &MenuId,
+) -> bool {
+    // release the borrow so the handler can replace itself
+    let handler = action_handler.borrow().clone();
+    ActionHandler::run(handler.as_ref(), item_type, id)
+}
+
+/// Runs the `Copy`, `Cut`, `Paste`, `SelectAll` or `Delete` command on `widget`,
+/// the focused widget of the window the item acts on.
+///
+/// Widgets that don't support the command get its shortcut instead, see [`send_edit_shortcut`].
+fn execute_edit_command(widget: &gtk::Widget, command: &PredefinedMenuItemType) {
+    #[cfg(feature = "webkit2gtk")]
+    if let Some(webview) = widget.downcast_ref::<webkit2gtk::WebView>() {
+        execute_webview_command(webview, command);
+        return;
+    }
+
+    if let Some(editable) = widget.dynamic_cast_ref::<gtk::Editable>() {
+        match command {
+            PredefinedMenuItemType::Copy => editable.copy_clipboard(),
+            PredefinedMenuItemType::Cut => editable.cut_clipboard(),
+            PredefinedMenuItemType::Paste => editable.paste_clipboard(),
+            PredefinedMenuItemType::SelectAll => editable.select_region(0, -1),
+            PredefinedMenuItemType::Delete => editable.delete_selection(),
+            _ => send_edit_shortcut(Some(widget), command),
+        }
+    } else if let Some(text_view) = widget.downcast_ref::<gtk::TextView>() {
+        match command {
+            PredefinedMenuItemType::Copy => text_view.emit_copy_clipboard(),
+            PredefinedMenuItemType::Cut => text_view.emit_cut_clipboard(),
+            PredefinedMenuItemType::Paste => text_view.emit_paste_clipboard(),
+            PredefinedMenuItemType::SelectAll => text_view.emit_select_all(true),
+            PredefinedMenuItemType::Delete => match text_view.buffer() {
+                Some(buffer) => {
+                    buffer.delete_selection(true, text_view.is_editable());
+                }
+                None => send_edit_shortcut(Some(widget), command),
+            },
+            _ => send_edit_shortcut(Some(widget), command),
+        }
+    } else if let Some(label) = widget.downcast_ref::<gtk::Label>() {
+        match command {
+            PredefinedMenuItemType::Copy => label.emit_copy_clipboard(),
+            PredefinedMenuItemType::SelectAll => label.select_region(0, -1),
+            _ => send_edit_shortcut(Some(widget), command),
+        }
+    } else {
+        send_edit_shortcut(Some(widget), command);
+    }
+}
+
+/// Runs `command` on a WebKitGTK webview, like the webviews of wry,
+/// or sends its shortcut if WebKit can't execute it, see [`send_edit_shortcut`].
+#[cfg(feature = "webkit2gtk")]
+fn execute_webview_command(webview: &webkit2gtk::WebView, command: &PredefinedMenuItemType) {
+    use webkit2gtk::WebViewExt;
+
+    let name = match command {
+        PredefinedMenuItemType::Copy => "Copy",
+        PredefinedMenuItemType::Cut => "Cut",
+        PredefinedMenuItemType::Paste => "Paste",
+        PredefinedMenuItemType::SelectAll => "SelectAll",
+        PredefinedMenuItemType::Delete => "Delete",
+        _ => return send_edit_shortcut(Some(webview.upcast_ref()), command),
+    };
+
+    let webview_ = webview.clone();
+    let command = command.clone();
+    can_execute_editing_command(webview, name, move |can_execute| {
+        if can_execute {
+            webview_.execute_editing_command(name);
+        } else {
+            send_edit_shortcut(Some(webview_.upcast_ref()), &command);
+        }
+    });
+}
+
+/// Calls `callback` with whether WebKit can execute the editing `command` on `webview`.
+///
+/// `WebViewExt::can_execute_editing_command` drops the result of the check,
+/// so this calls `webkit_web_view_can_execute_editing_command` through the bindings of webkit2gtk.
+#[cfg(feature = "webkit2gtk")]
+fn can_execute_editing_command<F: FnOnce(bool) + 'static>(
+    webview: &webkit2gtk::WebView,
+    command: &str,
+    callback: F,
+) {
+    use webkit2gtk::ffi;
+
+    unsafe extern "C" fn trampoline<F: FnOnce(bool) + 'static>(
+        source: *mut glib::gobject_ffi::GObject,
+        result: *mut gtk::gio::ffi::GAsyncResult,
+        user_data: glib::ffi::gpointer,
+    ) {
+        let mut error = std::ptr::null_mut();
+        // `source` is the webview the check was started on and `result` is its result
+        let can_execute = ffi::webkit_web_view_can_execute_editing_command_finish(
+            source.cast(),
+            result,
+            &mut error,
+        ) != glib::ffi::GFALSE;
+        if !error.is_null() {
+            // the error is owned by the caller of `_finish`
+            glib::ffi::g_error_free(error);
+        }
+        // `user_data` is the callback boxed below, GIO calls this trampoline exactly once
+        let callback = Box::from_raw(user_data as *mut F);
+        callback(can_execute);
+    }
+
+    let callback = Box::into_raw(Box::new(callback));
+    // WebKit copies `command` and keeps a reference to the webview until the check completes
+    unsafe {
+        ffi::webkit_web_view_can_execute_editing_command(
+            webview.to_glib_none().0,
+            command.to_glib_none().0,
+            std::ptr::null_mut(),
+            Some(trampoline::<F>),
+            callback.cast(),
+        );
+    }
+}
+
+/// Sends the shortcut of `command`, like `Ctrl+C`, to `widget` as a key press, see [`send_key_press`].
+///
+/// If there is no `widget` or it doesn't handle the shortcut, the shortcut is sent
+/// to the system with libxdo instead, which only works on X11.
+fn send_edit_shortcut(widget: Option<&gtk::Widget>, command: &PredefinedMenuItemType) {
+    if !widget.is_some_and(|widget| send_key_press(widget, command)) {
+        #[cfg(feature = "libxdo")]
+        if let Ok(xdo) = libxdo::XDo::new(None) {
+            let _ = xdo.send_keysequence(command.xdo_keys(), 0);
+        }
+    }
+}
+
+/// Sends the shortcut of `command` to `widget` as a synthesized key press,
+/// returns whether `widget` handled it.
+///
+/// Unlike libxdo, this also works on Wayland, but only for widgets that handle the shortcut themselves.
+fn send_key_press(widget: &gtk::Widget, command: &PredefinedMenuItemType) -> bool {
+    use gdk::keys::constants as keys;
+
+    let (key, state) = match command {
+        PredefinedMenuItemType::Copy => (keys::c, gdk::ModifierType::CONTROL_MASK),
+        PredefinedMenuItemType::Cut => (keys::x, gdk::ModifierType::CONTROL_MASK),
+        PredefinedMenuItemType::Paste => (keys::v, gdk::ModifierType::CONTROL_MASK),
+        PredefinedMenuItemType::SelectAll => (keys::a, gdk::ModifierType::CONTROL_MASK),
+        PredefinedMenuItemType::Delete => (keys::Delete, gdk::ModifierType::empty()),
+        _ => return false,
+    };
+
+    let window = match widget.window() {
+        Some(window) => window,
+        None => return false,
+    };
+    let display = widget.display();
+    let keycode = gdk::Keymap::for_display(&display)
+        .and_then(|keymap| {
+            keymap
+                .entries_for_keyval(key.into_glib())
+                .first()
+                .map(|k| k.keycode())
+        })
+        .unwrap_or_default();
+
+    let mut event = gdk::Event::new(gdk::EventType::KeyPress);
+    if let Some(keyboard) = display.default_seat().and_then(|seat| seat.keyboard()) {
+        event.set_device(Some(&keyboard));
+    }
+    // gtk-rs has no setters for the fields of key events, so they are written directly
+    unsafe {
+        let raw: *mut gdk::ffi::GdkEvent = event.to_glib_none_mut().0;
+        // `event` was created as a `GDK_KEY_PRESS`, so the `GdkEvent` union holds a `GdkEventKey`
+        let key_event = &mut *(raw as *mut gdk::ffi::GdkEventKey);
+        // the window field is unset in a new event, and `gdk_event_free` unrefs it,
+        // so the event gets its own reference
+        key_event.window = window.to_glib_full();
+        // marks the event as synthesized, like `gdk_event_put` users do
+        key_event.send_event = 1;
+        key_event.time = gtk::current_event_time();
+        key_event.state = state.into_glib();
+        key_event.keyval = key.into_glib();
+        // keycodes fit in 8 bits on X11 and in 16 bits on Wayland
+        key_event.hardware_keycode = keycode as u16;
+    }
+
+    widget.event(&event)
+}
+
+thread_local! {
+    /// The window of the context menu that is currently shown.
+    static CONTEXT_MENU_WINDOW: RefCell<Option<gtk::Window>> = const { RefCell::new(None) };
+}
+
+/// Returns the window `item` acts on, the window of the menubar it belongs to,
+/// or the window a context menu was shown for.
+fn window_for_gtk_menu_item(item: &gtk::MenuItem) -> Option<gtk::Window> {
+    let mut widget = item.clone().upcast::<gtk::Widget>();
+    loop {
+        widget = match widget.parent() {
+            // submenus are attached to the item that opens them
+            Some(parent) => match parent.downcast::<gtk::Menu>() {
+                Ok(menu) => match menu.attach_widget() {
+                    Some(attach_widget) => attach_widget,
+                    None => return CONTEXT_MENU_WINDOW.with(|w| w.borrow().clone()),
+                },
+                Err(parent) => parent,
+            },
+            None => return widget.downcast::<gtk::Window>().ok(),
+        };
+    }
+}
+
 fn show_context_menu(
     gtk_menu: gtk::Menu,
     widget: &impl IsA<gtk::Widget>,
@@ -1416,6 +2077,11 @@ fn show_context_menu(
         let id = gtk_menu.connect_cancel(move |_| tx_clone.send(false).unwrap_or(()));
         let id2 = gtk_menu.connect_selection_done(move |_| tx.send(true).unwrap_or(()));
 
+        let toplevel = widget
+            .toplevel()
+            .and_then(|w| w.downcast::<gtk::Window>().ok());
+        let previous_window = CONTEXT_MENU_WINDOW.with(|w| w.replace(toplevel));
+
         gtk_menu.popup_at_rect(
             &window,
             &gdk::Rectangle::new(pos.0, pos.1, 0, 0),
@@ -1424,30 +2090,66 @@ fn show_context_menu(
             Some(&event),
         );
 
-        loop {
+        let result = loop {
             gtk::main_iteration();
 
             match rx.try_recv() {
-                Ok(result) => {
-                    gtk_menu.disconnect(id);
-                    gtk_menu.disconnect(id2);
-                    return result;
-                }
+                Ok(result) => break result,
                 Err(err) => {
                     if err.is_disconnected() {
-                        gtk_menu.disconnect(id);
-                        gtk_menu.disconnect(id2);
-                        return false;
+                        break false;
                     }
                 }
             }
-        }
+        };
+
+        gtk_menu.disconnect(id);
+        gtk_menu.disconnect(id2);
+        CONTEXT_MENU_WINDOW.with(|w| w.replace(previous_window));
+
+        return result;
     }
 
     false
 }
 
 impl PredefinedMenuItemType {
+    /// Returns `label`, or the built-in English text, with `app_name` after the text of About items.
+    pub(crate) fn platform_text(&self, label: Option<String>, app_name: Option<&str>) -> String {
+        let text = label.unwrap_or_else(|| self.text().to_string());
+        match (self, app_name) {
+            (PredefinedMenuItemType::About(_), Some(app_name)) => format!("{text} {app_name}"),
+            _ => text,
+        }
+    }
+
+    pub(crate) fn is_platform_supported(&self) -> bool {
+        matches!(
+            self,
+            PredefinedMenuItemType::Separator
+                | PredefinedMenuItemType::Copy
+                | PredefinedMenuItemType::Cut
+                | PredefinedMenuItemType::Paste
+                | PredefinedMenuItemType::SelectAll
+                | PredefinedMenuItemType::Minimize
+                | PredefinedMenuItemType::Maximize
+                | PredefinedMenuItemType::Fullscreen
+                | PredefinedMenuItemType::Hide
+                | PredefinedMenuItemType::CloseWindow
+                | PredefinedMenuItemType::Quit
+                | PredefinedMenuItemType::About(_)
+                | PredefinedMenuItemType::Delete
+                | PredefinedMenuItemType::Find
+                | PredefinedMenuItemType::FindNext
+                | PredefinedMenuItemType::Preferences
+                | PredefinedMenuItemType::Help
+                | PredefinedMenuItemType::NewWindow
+                | PredefinedMenuItemType::ZoomIn
+                | PredefinedMenuItemType::ZoomOut
+                | PredefinedMenuItemType::ZoomReset
+        )
+    }
+
     #[cfg(feature = "libxdo")]
     fn xdo_keys(&self) -> &str {
         match self {
@@ -1455,6 +2157,7 @@ impl PredefinedMenuItemType {
             PredefinedMenuItemType::Cut => "ctrl+X",
             PredefinedMenuItemType::Paste => "ctrl+v",
             PredefinedMenuItemType::SelectAll => "ctrl+a",
+            PredefinedMenuItemType::Delete => "Delete",
             _ => unreachable!(),
         }
     }
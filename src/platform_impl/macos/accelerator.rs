@@ -103,6 +103,51 @@ impl Accelerator {
             Code::F22 => "\u{F719}".into(),
             Code::F23 => "\u{F71A}".into(),
             Code::F24 => "\u{F71B}".into(),
+            Code::F25 => "\u{F71C}".into(),
+            Code::F26 => "\u{F71D}".into(),
+            Code::F27 => "\u{F71E}".into(),
+            Code::F28 => "\u{F71F}".into(),
+            Code::F29 => "\u{F720}".into(),
+            Code::F30 => "\u{F721}".into(),
+            Code::F31 => "\u{F722}".into(),
+            Code::F32 => "\u{F723}".into(),
+            Code::F33 => "\u{F724}".into(),
+            Code::F34 => "\u{F725}".into(),
+            Code::F35 => "\u{F726}".into(),
+            Code::Pause => "\u{F730}".into(),
+            Code::ContextMenu => "\u{F735}".into(),
+            Code::NumpadClear | Code::NumpadClearEntry => "\u{F739}".into(),
+            Code::Select => "\u{F741}".into(),
+            Code::Undo => "\u{F743}".into(),
+            Code::Again => "\u{F744}".into(),
+            Code::Find => "\u{F745}".into(),
+            Code::Help => "\u{F746}".into(),
+            // numpad keys are told apart by `key_modifier_mask`
+            Code::Numpad0 => "0".into(),
+            Code::Numpad1 => "1".into(),
+            Code::Numpad2 => "2".into(),
+            Code::Numpad3 => "3".into(),
+            Code::Numpad4 => "4".into(),
+            Code::Numpad5 => "5".into(),
+            Code::Numpad6 => "6".into(),
+            Code::Numpad7 => "7".into(),
+            Code::Numpad8 => "8".into(),
+            Code::Numpad9 => "9".into(),
+            Code::NumpadAdd => "+".into(),
+            Code::NumpadSubtract => "-".into(),
+            Code::NumpadMultiply | Code::NumpadStar => "*".into(),
+            Code::NumpadDivide => "/".into(),
+            Code::NumpadDecimal => ".".into(),
+            Code::NumpadComma => ",".into(),
+            Code::NumpadEqual => "=".into(),
+            Code::NumpadHash => "#".into(),
+            Code::NumpadParenLeft => "(".into(),
+            Code::NumpadParenRight => ")".into(),
+            Code::NumpadEnter => "\u{0003}".into(),
+            Code::NumpadBackspace => "\u{0008}".into(),
+            Code::IntlBackslash => "§".into(),
+            Code::IntlYen => "¥".into(),
+            Code::IntlRo => "_".into(),
             key => return Err(AcceleratorParseError::UnsupportedKey(key.to_string())),
         })
     }
@@ -123,6 +168,38 @@ impl Accelerator {
         if mods.contains(Modifiers::CONTROL) {
             flags.insert(NSEventModifierFlags::NSEventModifierFlagControl);
         }
+        if is_numpad_key(self.key) {
+            flags.insert(NSEventModifierFlags::NSEventModifierFlagNumericPad);
+        }
         flags
     }
 }
+
+fn is_numpad_key(key: Code) -> bool {
+    matches!(
+        key,
+        Code::Numpad0
+            | Code::Numpad1
+            | Code::Numpad2
+            | Code::Numpad3
+            | Code::Numpad4
+            | Code::Numpad5
+            | Code::Numpad6
+            | Code::Numpad7
+            | Code::Numpad8
+            | Code::Numpad9
+            | Code::NumpadAdd
+            | Code::NumpadSubtract
+            | Code::NumpadMultiply
+            | Code::NumpadStar
+            | Code::NumpadDivide
+            | Code::NumpadDecimal
+            | Code::NumpadComma
+            | Code::NumpadEqual
+            | Code::NumpadHash
+            | Code::NumpadParenLeft
+            | Code::NumpadParenRight
+            | Code::NumpadEnter
+            | Code::NumpadBackspace
+    )
+}
@@ -9,7 +9,7 @@ use objc2_foundation::{CGFloat, NSData, NSSize};
 use crate::icon::{BadIcon, RgbaIcon};
 use std::io::Cursor;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PlatformIcon(RgbaIcon);
 
 impl PlatformIcon {
@@ -12,7 +12,7 @@ use std::{
     cell::{Cell, RefCell},
     collections::HashMap,
     ffi::c_void,
-    ptr,
+    mem, ptr,
     rc::Rc,
 };
 
@@ -37,9 +37,10 @@ use self::util::strip_mnemonic;
 use crate::{
     accelerator::Accelerator,
     dpi::{LogicalPosition, Position},
+    group::MenuGroups,
     icon::{Icon, NativeIcon},
     items::*,
-    util::{AddOp, Counter},
+    util::{move_element, AddOp, Counter},
     IsMenuItem, MenuEvent, MenuId, MenuItemKind, MenuItemType,
 };
 
@@ -63,6 +64,7 @@ pub struct Menu {
     id: MenuId,
     ns_menu: NsMenuRef,
     children: Vec<Rc<RefCell<MenuChild>>>,
+    groups: MenuGroups,
 }
 
 impl Drop for Menu {
@@ -87,6 +89,7 @@ impl Menu {
             id: id.unwrap_or_else(|| MenuId(COUNTER.next().to_string())),
             ns_menu: NsMenuRef(COUNTER.next(), ns_menu),
             children: Vec::new(),
+            groups: MenuGroups::default(),
         }
     }
 
@@ -94,6 +97,10 @@ impl Menu {
         &self.id
     }
 
+    pub fn groups_mut(&mut self) -> &mut MenuGroups {
+        &mut self.groups
+    }
+
     pub fn add_menu_item(&mut self, item: &dyn crate::IsMenuItem, op: AddOp) -> crate::Result<()> {
         let ns_menu_item = item.make_ns_item_for_menu(self.ns_menu.0)?;
         let child = item.child();
@@ -127,6 +134,24 @@ impl Menu {
             self.children.remove(index)
         };
 
+        self.remove_ns_menu_items(&child)
+    }
+
+    pub fn clear(&mut self) -> crate::Result<()> {
+        for child in mem::take(&mut self.children) {
+            self.remove_ns_menu_items(&child)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn item_at(&self, position: usize) -> Option<MenuItemKind> {
+        self.children
+            .get(position)
+            .map(|c| c.borrow().kind(c.clone()))
+    }
+
+    fn remove_ns_menu_items(&self, child: &Rc<RefCell<MenuChild>>) -> crate::Result<()> {
         let mut child_ = child.borrow_mut();
 
         if child_.item_type == MenuItemType::Submenu {
@@ -152,6 +177,12 @@ impl Menu {
         Ok(())
     }
 
+    pub fn move_item(&mut self, from: usize, to: usize) -> crate::Result<()> {
+        move_element(&mut self.children, from, to)?;
+        unsafe { move_ns_menu_item(&self.ns_menu.1, from, to) };
+        Ok(())
+    }
+
     pub fn items(&self) -> Vec<MenuItemKind> {
         self.children
             .iter()
@@ -192,6 +223,7 @@ pub struct MenuChild {
     item_type: MenuItemType,
     id: MenuId,
     text: String,
+    text_key: Option<String>,
     enabled: bool,
 
     ns_menu_items: HashMap<u32, Vec<Retained<NSMenuItem>>>,
@@ -201,6 +233,7 @@ pub struct MenuChild {
 
     // predefined menu item fields
     predefined_item_type: Option<PredefinedMenuItemType>,
+    action_handler: Option<ActionHandler>,
 
     // check menu item fields
     checked: Cell<bool>,
@@ -211,6 +244,7 @@ pub struct MenuChild {
 
     // submenu fields
     pub children: Option<Vec<Rc<RefCell<MenuChild>>>>,
+    pub groups: Option<MenuGroups>,
     ns_menus: Option<HashMap<u32, Vec<NsMenuRef>>>,
     ns_menu: Option<NsMenuRef>,
 }
@@ -262,6 +296,9 @@ impl MenuChild {
             accelerator,
             checked: Cell::new(false),
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             icon: None,
             native_icon: None,
             ns_menu: None,
@@ -284,6 +321,9 @@ impl MenuChild {
             id: id.unwrap_or_else(|| MenuId(COUNTER.next().to_string())),
             enabled,
             children: Some(Vec::new()),
+            groups: Some(MenuGroups::default()),
+            text_key: None,
+            action_handler: None,
             ns_menu: Some(NsMenuRef(COUNTER.next(), unsafe {
                 let menu = NSMenu::new(mtm);
                 menu.setAutoenablesItems(false);
@@ -299,25 +339,12 @@ impl MenuChild {
         }
     }
 
-    pub(crate) fn new_predefined(item_type: PredefinedMenuItemType, text: Option<String>) -> Self {
-        let text = strip_mnemonic(text.unwrap_or_else(|| {
-            // Gets the app's name from `NSRunningApplication::localizedName`.
-            let app_name = || unsafe {
-                let app = NSRunningApplication::currentApplication();
-                app.localizedName().unwrap_or_default()
-            };
-
-            match item_type {
-                PredefinedMenuItemType::About(_) => {
-                    format!("About {}", app_name()).trim().to_string()
-                }
-                PredefinedMenuItemType::Hide => format!("Hide {}", app_name()).trim().to_string(),
-                PredefinedMenuItemType::Quit => format!("Quit {}", app_name()).trim().to_string(),
-                _ => item_type.text().to_string(),
-            }
-        }));
-        let accelerator = item_type.accelerator();
-
+    pub(crate) fn new_predefined(
+        item_type: PredefinedMenuItemType,
+        text: Option<String>,
+        accelerator: Option<Accelerator>,
+    ) -> Self {
+        let text = strip_mnemonic(text.unwrap_or_else(|| item_type.platform_text(None, None)));
         Self {
             item_type: MenuItemType::Predefined,
             text,
@@ -327,6 +354,9 @@ impl MenuChild {
             predefined_item_type: Some(item_type),
             checked: Cell::new(false),
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             icon: None,
             native_icon: None,
             ns_menu: None,
@@ -350,6 +380,9 @@ impl MenuChild {
             accelerator,
             checked: Cell::new(checked),
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             icon: None,
             native_icon: None,
             ns_menu: None,
@@ -375,6 +408,9 @@ impl MenuChild {
             accelerator,
             checked: Cell::new(false),
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             native_icon: None,
             ns_menu: None,
             ns_menu_items: HashMap::new(),
@@ -399,6 +435,9 @@ impl MenuChild {
             accelerator,
             checked: Cell::new(false),
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             icon: None,
             ns_menu: None,
             ns_menu_items: HashMap::new(),
@@ -418,6 +457,30 @@ impl MenuChild {
         &self.id
     }
 
+    pub fn accelerator(&self) -> Option<Accelerator> {
+        self.accelerator
+    }
+
+    pub fn predefined_item_type(&self) -> Option<PredefinedMenuItemType> {
+        self.predefined_item_type.clone()
+    }
+
+    pub fn icon(&self) -> Option<Icon> {
+        self.icon.clone()
+    }
+
+    pub fn native_icon(&self) -> Option<NativeIcon> {
+        self.native_icon
+    }
+
+    pub fn text_key(&self) -> Option<String> {
+        self.text_key.clone()
+    }
+
+    pub fn set_text_key(&mut self, key: Option<String>) {
+        self.text_key = key;
+    }
+
     pub fn text(&self) -> String {
         self.text.clone()
     }
@@ -456,20 +519,19 @@ impl MenuChild {
             .map(|accel| accel.key_equivalent())
             .transpose()?;
 
-        if let Some(key_equivalent) = key_equivalent {
-            let key_equivalent = NSString::from_str(key_equivalent.as_str());
+        // an empty key equivalent removes the accelerator
+        let key_equivalent = NSString::from_str(&key_equivalent.unwrap_or_default());
 
-            let modifier_mask = (accelerator)
-                .as_ref()
-                .map(|accel| accel.key_modifier_mask())
-                .unwrap_or_else(NSEventModifierFlags::empty);
+        let modifier_mask = (accelerator)
+            .as_ref()
+            .map(|accel| accel.key_modifier_mask())
+            .unwrap_or_else(NSEventModifierFlags::empty);
 
-            for ns_items in self.ns_menu_items.values() {
-                for ns_item in ns_items {
-                    unsafe {
-                        ns_item.setKeyEquivalent(&key_equivalent);
-                        ns_item.setKeyEquivalentModifierMask(modifier_mask);
-                    }
+        for ns_items in self.ns_menu_items.values() {
+            for ns_item in ns_items {
+                unsafe {
+                    ns_item.setKeyEquivalent(&key_equivalent);
+                    ns_item.setKeyEquivalentModifierMask(modifier_mask);
                 }
             }
         }
@@ -480,6 +542,31 @@ impl MenuChild {
     }
 }
 
+/// PredefinedMenuItem methods
+impl MenuChild {
+    pub fn action_handler(&self) -> Option<ActionHandler> {
+        self.action_handler.clone()
+    }
+
+    pub fn set_action_handler(&mut self, handler: Option<ActionHandler>) {
+        self.action_handler = handler;
+    }
+
+    /// Returns the action of the native menu items of this predefined item.
+    ///
+    /// Items that can be overridden are handled in `fire_menu_item_click`, which looks up
+    /// the handler replacing their native action when they are clicked, see `set_action_handler`.
+    fn predefined_selector(&self) -> Option<Sel> {
+        let item_type = self.predefined_item_type.as_ref()?;
+        match item_type {
+            PredefinedMenuItemType::Separator | PredefinedMenuItemType::Services => {
+                item_type.selector()
+            }
+            _ => item_type.selector().map(|_| sel!(fireMenuItemAction:)),
+        }
+    }
+}
+
 /// CheckMenuItem methods
 impl MenuChild {
     pub fn is_checked(&self) -> bool {
@@ -598,6 +685,31 @@ impl MenuChild {
             }
         };
 
+        self.remove_ns_menu_items(&child, remove_from_cache, id)
+    }
+
+    pub fn clear(&mut self) -> crate::Result<()> {
+        for child in mem::take(self.children.as_mut().unwrap()) {
+            self.remove_ns_menu_items(&child, true, None)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn item_at(&self, position: usize) -> Option<MenuItemKind> {
+        self.children
+            .as_ref()
+            .unwrap()
+            .get(position)
+            .map(|c| c.borrow().kind(c.clone()))
+    }
+
+    fn remove_ns_menu_items(
+        &self,
+        child: &Rc<RefCell<MenuChild>>,
+        remove_from_cache: bool,
+        id: Option<u32>,
+    ) -> crate::Result<()> {
         for menus in self.ns_menus.as_ref().unwrap().values() {
             for menu in menus {
                 // check if we are removing this item from all ns_menus
@@ -647,6 +759,22 @@ impl MenuChild {
         Ok(())
     }
 
+    pub fn move_item(&mut self, from: usize, to: usize) -> crate::Result<()> {
+        move_element(self.children.as_mut().unwrap(), from, to)?;
+
+        unsafe {
+            for menus in self.ns_menus.as_ref().unwrap().values() {
+                for ns_menu in menus {
+                    move_ns_menu_item(&ns_menu.1, from, to);
+                }
+            }
+
+            move_ns_menu_item(&self.ns_menu.as_ref().unwrap().1, from, to);
+        }
+
+        Ok(())
+    }
+
     pub fn items(&self) -> Vec<MenuItemKind> {
         self.children
             .as_ref()
@@ -770,16 +898,16 @@ impl MenuChild {
         let ns_menu_item = match item_type {
             PredefinedMenuItemType::Separator => NSMenuItem::separatorItem(mtm),
             _ => {
-                let ns_menu_item =
-                    MenuItem::create(mtm, &self.text, item_type.selector(), &self.accelerator)?;
+                let selector = self.predefined_selector();
+                let ns_menu_item = MenuItem::create(mtm, &self.text, selector, &self.accelerator)?;
 
-                if let PredefinedMenuItemType::About(_) = item_type {
-                    unsafe {
+                unsafe {
+                    if selector == Some(sel!(fireMenuItemAction:)) {
                         ns_menu_item.setTarget(Some(&ns_menu_item));
-
-                        // Store a raw pointer to the `MenuChild` as an instance variable on the native menu item
-                        ns_menu_item.ivars().set(&*self);
                     }
+
+                    // Store a raw pointer to the `MenuChild` as an instance variable on the native menu item
+                    ns_menu_item.ivars().set(&*self);
                 }
 
                 Retained::into_super(ns_menu_item)
@@ -884,6 +1012,32 @@ impl MenuChild {
 }
 
 impl PredefinedMenuItemType {
+    /// Returns `label`, or the built-in English text, with the name of the app after the text
+    /// of About, Hide and Quit items, `app_name` or `NSRunningApplication::localizedName`.
+    pub(crate) fn platform_text(&self, label: Option<String>, app_name: Option<&str>) -> String {
+        let text = label.unwrap_or_else(|| self.text().to_string());
+        match self {
+            PredefinedMenuItemType::About(_)
+            | PredefinedMenuItemType::Hide
+            | PredefinedMenuItemType::Quit => {
+                let app_name = match app_name {
+                    Some(app_name) => app_name.to_string(),
+                    None => unsafe {
+                        let app = NSRunningApplication::currentApplication();
+                        app.localizedName().unwrap_or_default().to_string()
+                    },
+                };
+                format!("{text} {app_name}").trim().to_string()
+            }
+            _ => text,
+        }
+    }
+
+    pub(crate) fn is_platform_supported(&self) -> bool {
+        // every predefined menu item has a native selector or emits a `MenuEvent`
+        true
+    }
+
     pub(crate) fn selector(&self) -> Option<Sel> {
         match self {
             PredefinedMenuItemType::Separator => None,
@@ -905,7 +1059,16 @@ impl PredefinedMenuItemType {
             PredefinedMenuItemType::About(_) => Some(sel!(fireMenuItemAction:)),
             PredefinedMenuItemType::Services => None,
             PredefinedMenuItemType::BringAllToFront => Some(sel!(arrangeInFront:)),
-            PredefinedMenuItemType::None => None,
+            PredefinedMenuItemType::Delete => Some(sel!(delete:)),
+            PredefinedMenuItemType::Help => Some(sel!(showHelp:)),
+            // emit a `MenuEvent` in `fire_menu_item_click`
+            PredefinedMenuItemType::Find
+            | PredefinedMenuItemType::FindNext
+            | PredefinedMenuItemType::Preferences
+            | PredefinedMenuItemType::NewWindow
+            | PredefinedMenuItemType::ZoomIn
+            | PredefinedMenuItemType::ZoomOut
+            | PredefinedMenuItemType::ZoomReset => Some(sel!(fireMenuItemAction:)),
         }
     }
 }
@@ -968,11 +1131,40 @@ impl MenuItem {
 
     fn fire_menu_item_click(&self) {
         let mtm = MainThreadMarker::from(self);
+
+        // copy what the handler replacing the native action needs and drop the reference
+        // to the `MenuChild` before running it, so the handler can update or drop the item
+        let action = {
+            let item = unsafe { self.ivars().get().as_ref() }
+                .expect("MenuItem's MenuChild pointer was unset");
+            item.predefined_item_type
+                .clone()
+                .map(|item_type| (item.action_handler.clone(), item_type, item.id.clone()))
+        };
+        if let Some((handler, item_type, id)) = action {
+            if ActionHandler::run(handler.as_ref(), &item_type, &id) {
+                return;
+            }
+        }
+
         // Create a reference to the `MenuChild` from the raw pointer
         // stored as an instance variable on the native menu item
         let item =
             unsafe { self.ivars().get().as_ref() }.expect("MenuItem's MenuChild pointer was unset");
 
+        // there is no handler replacing the native action, run it
+        if let Some(selector) = item
+            .predefined_item_type
+            .as_ref()
+            .and_then(|t| t.selector())
+            .filter(|s| *s != sel!(fireMenuItemAction:))
+        {
+            unsafe {
+                NSApplication::sharedApplication(mtm).sendAction_to_from(selector, None, Some(self))
+            };
+            return;
+        }
+
         if let Some(PredefinedMenuItemType::About(about_meta)) = &item.predefined_item_type {
             match about_meta {
                 Some(about_meta) => {
@@ -1071,6 +1263,14 @@ impl MenuItem {
     }
 }
 
+/// Moves the item at index `from` to index `to` in `ns_menu` without recreating it.
+unsafe fn move_ns_menu_item(ns_menu: &NSMenu, from: usize, to: usize) {
+    if let Some(item) = ns_menu.itemAtIndex(from as NSInteger) {
+        ns_menu.removeItemAtIndex(from as NSInteger);
+        ns_menu.insertItem_atIndex(&item, to as NSInteger);
+    }
+}
+
 fn menuitem_set_icon(menuitem: &NSMenuItem, icon: Option<&Icon>) {
     if let Some(icon) = icon {
         unsafe {
@@ -4,13 +4,23 @@
 
 use std::str;
 
-/// Strips single `&` characters from the string.
+/// Strips single `&` characters from the string, and `(&X)` mnemonics used by CJK languages.
 ///
 /// `&` can be escaped as `&&` to prevent stripping, in which case a single `&` will be output.
 pub fn strip_mnemonic<S: AsRef<str>>(string: S) -> String {
-    string
-        .as_ref()
-        .replace("&&", "[~~]")
-        .replace('&', "")
-        .replace("[~~]", "&")
+    crate::mnemonic::strip(&strip_parenthesized_mnemonic(string.as_ref()))
+}
+
+/// Strips a `(&X)` mnemonic, like in `コピー(&C)`.
+fn strip_parenthesized_mnemonic(string: &str) -> String {
+    if let Some(start) = string.find("(&") {
+        let rest = &string[start + 2..];
+        let mut chars = rest.chars();
+        if let (Some(c), Some(')')) = (chars.next(), chars.next()) {
+            if c.is_ascii_alphanumeric() {
+                return format!("{}{}", &string[..start], &rest[c.len_utf8() + 1..]);
+            }
+        }
+    }
+    string.to_string()
 }
@@ -31,6 +31,11 @@ impl dyn IsMenuItem + '_ {
             MenuItemKind::Icon(i) => i.inner,
         }
     }
+
+    /// Returns `true` if `self` and `other` are handles to the same menu item.
+    pub(crate) fn is_same_item(&self, other: &dyn IsMenuItem) -> bool {
+        Rc::ptr_eq(&self.child(), &other.child())
+    }
 }
 
 /// Internal utilities
@@ -162,6 +162,49 @@ fn key_to_vk(key: &Code) -> Result<VIRTUAL_KEY, AcceleratorParseError> {
         Code::MediaPlayPause => VK_MEDIA_PLAY_PAUSE,
         Code::LaunchMail => VK_LAUNCH_MAIL,
         Code::Convert => VK_CONVERT,
+        Code::IntlBackslash => VK_OEM_102,
+        Code::IntlRo => VK_ABNT_C1,
+        Code::Numpad0 => VK_NUMPAD0,
+        Code::Numpad1 => VK_NUMPAD1,
+        Code::Numpad2 => VK_NUMPAD2,
+        Code::Numpad3 => VK_NUMPAD3,
+        Code::Numpad4 => VK_NUMPAD4,
+        Code::Numpad5 => VK_NUMPAD5,
+        Code::Numpad6 => VK_NUMPAD6,
+        Code::Numpad7 => VK_NUMPAD7,
+        Code::Numpad8 => VK_NUMPAD8,
+        Code::Numpad9 => VK_NUMPAD9,
+        Code::NumpadAdd => VK_ADD,
+        Code::NumpadSubtract => VK_SUBTRACT,
+        Code::NumpadMultiply | Code::NumpadStar => VK_MULTIPLY,
+        Code::NumpadDivide => VK_DIVIDE,
+        Code::NumpadDecimal => VK_DECIMAL,
+        Code::NumpadComma => VK_SEPARATOR,
+        // Accelerator tables can't tell the two enter keys apart.
+        Code::NumpadEnter => VK_RETURN,
+        Code::NumpadEqual => VK_OEM_NEC_EQUAL,
+        Code::NumpadBackspace => VK_BACK,
+        Code::NumpadClear => VK_CLEAR,
+        Code::NumpadClearEntry => VK_OEM_CLEAR,
+        Code::ShiftLeft => VK_LSHIFT,
+        Code::ShiftRight => VK_RSHIFT,
+        Code::ControlLeft => VK_LCONTROL,
+        Code::ControlRight => VK_RCONTROL,
+        Code::AltLeft => VK_LMENU,
+        Code::AltRight => VK_RMENU,
+        Code::MetaLeft => VK_LWIN,
+        Code::MetaRight => VK_RWIN,
+        Code::Lang1 => VK_HANGUL,
+        Code::Lang2 => VK_HANJA,
+        Code::Hiragana => VK_DBE_HIRAGANA,
+        Code::Katakana => VK_DBE_KATAKANA,
+        Code::Select => VK_SELECT,
+        Code::Sleep => VK_SLEEP,
+        Code::LaunchApp1 => VK_LAUNCH_APP1,
+        Code::LaunchApp2 => VK_LAUNCH_APP2,
+        Code::MediaSelect => VK_LAUNCH_MEDIA_SELECT,
+        Code::MediaPlay => VK_PLAY,
+        Code::ZoomToggle => VK_ZOOM,
         key => return Err(AcceleratorParseError::UnsupportedKey(key.to_string())),
     })
 }
@@ -241,6 +284,22 @@ impl fmt::Display for Accelerator {
             Code::ArrowRight => write!(f, "Right"),
             Code::ArrowUp => write!(f, "Up"),
             Code::ArrowDown => write!(f, "Down"),
+            Code::Numpad0 => write!(f, "Num 0"),
+            Code::Numpad1 => write!(f, "Num 1"),
+            Code::Numpad2 => write!(f, "Num 2"),
+            Code::Numpad3 => write!(f, "Num 3"),
+            Code::Numpad4 => write!(f, "Num 4"),
+            Code::Numpad5 => write!(f, "Num 5"),
+            Code::Numpad6 => write!(f, "Num 6"),
+            Code::Numpad7 => write!(f, "Num 7"),
+            Code::Numpad8 => write!(f, "Num 8"),
+            Code::Numpad9 => write!(f, "Num 9"),
+            Code::NumpadAdd => write!(f, "Num +"),
+            Code::NumpadSubtract => write!(f, "Num -"),
+            Code::NumpadMultiply | Code::NumpadStar => write!(f, "Num *"),
+            Code::NumpadDivide => write!(f, "Num /"),
+            Code::NumpadDecimal => write!(f, "Num ."),
+            Code::ContextMenu => write!(f, "Menu"),
             _ => write!(f, "{:?}", self.key),
         }
     }
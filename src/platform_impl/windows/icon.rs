@@ -74,6 +74,14 @@ pub(crate) struct WinIcon {
 
 unsafe impl Send for WinIcon {}
 
+/// Icons loaded from a path or a resource don't keep their pixels,
+/// so an icon is only equal to itself and its clones.
+impl PartialEq for WinIcon {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
 impl WinIcon {
     pub unsafe fn to_hbitmap(&self) -> HBITMAP {
         let hdc = CreateCompatibleDC(std::ptr::null_mut());
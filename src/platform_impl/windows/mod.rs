@@ -13,17 +13,13 @@ pub(crate) use self::icon::WinIcon as PlatformIcon;
 use crate::{
     accelerator::Accelerator,
     dpi::Position,
+    group::MenuGroups,
     icon::{Icon, NativeIcon},
-    items::PredefinedMenuItemType,
-    util::{AddOp, Counter},
+    items::{ActionHandler, PredefinedMenuItemType},
+    util::{defer, move_element, AddOp, Counter},
     AboutMetadata, IsMenuItem, MenuEvent, MenuId, MenuItemKind, MenuItemType, MenuTheme,
 };
-use std::{
-    cell::{RefCell, RefMut},
-    collections::HashMap,
-    fmt::Debug,
-    rc::Rc,
-};
+use std::{cell::RefCell, collections::HashMap, fmt::Debug, rc::Rc};
 use util::{decode_wide, encode_wide, Accel};
 use windows_sys::Win32::{
     Foundation::{LPARAM, LRESULT, POINT, WPARAM},
@@ -31,18 +27,19 @@ use windows_sys::Win32::{
     UI::{
         Input::KeyboardAndMouse::{
             GetActiveWindow, SendInput, INPUT, INPUT_KEYBOARD, KEYEVENTF_KEYUP, VK_CONTROL,
+            VK_DELETE,
         },
         Shell::{DefSubclassProc, RemoveWindowSubclass, SetWindowSubclass},
         WindowsAndMessaging::{
             AppendMenuW, CreateAcceleratorTableW, CreateMenu, CreatePopupMenu,
             DestroyAcceleratorTable, DestroyMenu, DrawMenuBar, EnableMenuItem, GetCursorPos,
-            GetMenu, GetMenuItemInfoW, InsertMenuW, PostMessageW, PostQuitMessage, RemoveMenu,
-            SendMessageW, SetForegroundWindow, SetMenu, SetMenuItemInfoW, ShowWindow,
+            GetMenu, GetMenuItemInfoW, InsertMenuItemW, InsertMenuW, PostMessageW, PostQuitMessage,
+            RemoveMenu, SendMessageW, SetForegroundWindow, SetMenu, SetMenuItemInfoW, ShowWindow,
             TrackPopupMenu, HACCEL, HMENU, MENUITEMINFOW, MFS_CHECKED, MFS_DISABLED, MF_BYCOMMAND,
             MF_BYPOSITION, MF_CHECKED, MF_DISABLED, MF_ENABLED, MF_GRAYED, MF_POPUP, MF_SEPARATOR,
-            MF_STRING, MF_UNCHECKED, MIIM_BITMAP, MIIM_STATE, MIIM_STRING, SW_HIDE, SW_MAXIMIZE,
-            SW_MINIMIZE, TPM_LEFTALIGN, TPM_RETURNCMD, WM_CLOSE, WM_COMMAND, WM_NCACTIVATE,
-            WM_NCPAINT,
+            MF_STRING, MF_UNCHECKED, MIIM_BITMAP, MIIM_DATA, MIIM_FTYPE, MIIM_ID, MIIM_STATE,
+            MIIM_STRING, MIIM_SUBMENU, SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, TPM_LEFTALIGN,
+            TPM_RETURNCMD, WM_CLOSE, WM_COMMAND, WM_NCACTIVATE, WM_NCPAINT,
         },
     },
 };
@@ -68,7 +65,6 @@ macro_rules! inner_menu_child_and_flags {
                 let child = i.inner;
                 let child_ = child.borrow();
                 match child_.predefined_item_type.as_ref().unwrap() {
-                    PredefinedMenuItemType::None => return Ok(()),
                     PredefinedMenuItemType::Separator => {
                         flags |= MF_SEPARATOR;
                     }
@@ -108,6 +104,7 @@ pub(crate) struct Menu {
     hwnds: Rc<RefCell<HashMap<Hwnd, MenuTheme>>>,
     haccel_store: Rc<RefCell<AccelWrapper>>,
     children: Vec<Rc<RefCell<MenuChild>>>,
+    groups: MenuGroups,
 }
 
 impl Drop for Menu {
@@ -163,6 +160,7 @@ impl Menu {
             hpopupmenu: unsafe { CreatePopupMenu() },
             haccel_store: Rc::new(RefCell::new((std::ptr::null_mut(), HashMap::new()))),
             children: Vec::new(),
+            groups: MenuGroups::default(),
             hwnds: Rc::new(RefCell::new(HashMap::new())),
         }
     }
@@ -171,6 +169,10 @@ impl Menu {
         &self.id
     }
 
+    pub fn groups_mut(&mut self) -> &mut MenuGroups {
+        &mut self.groups
+    }
+
     pub fn add_menu_item(&mut self, item: &dyn IsMenuItem, op: AddOp) -> crate::Result<()> {
         let (child, mut flags) = inner_menu_child_and_flags!(item);
 
@@ -195,11 +197,7 @@ impl Menu {
                 text.push('\t');
                 text.push_str(&accel_str);
 
-                AccelAction::add(
-                    &mut self.haccel_store.borrow_mut(),
-                    child_.internal_id(),
-                    accelerator,
-                )?;
+                AccelAction::add(&self.haccel_store, child_.internal_id(), accelerator)?;
             }
 
             let id = child_.internal_id() as usize;
@@ -251,7 +249,7 @@ impl Menu {
 
         // redraw the menu bar
         for hwnd in self.hwnds.borrow().keys() {
-            unsafe { DrawMenuBar(*hwnd as _) };
+            redraw_menu_bar(*hwnd);
         }
 
         {
@@ -277,11 +275,11 @@ impl Menu {
         unsafe {
             RemoveMenu(self.hmenu, id, MF_BYCOMMAND);
             RemoveMenu(self.hpopupmenu, id, MF_BYCOMMAND);
+        }
 
-            // redraw the menu bar
-            for hwnd in self.hwnds.borrow().keys() {
-                DrawMenuBar(*hwnd as _);
-            }
+        // redraw the menu bar
+        for hwnd in self.hwnds.borrow().keys() {
+            redraw_menu_bar(*hwnd);
         }
 
         let child = item.child();
@@ -312,6 +310,41 @@ impl Menu {
         Ok(())
     }
 
+    pub fn move_item(&mut self, from: usize, to: usize) -> crate::Result<()> {
+        move_element(&mut self.children, from, to)?;
+
+        unsafe {
+            move_menu_item(self.hmenu, from, to);
+            move_menu_item(self.hpopupmenu, from, to);
+        }
+
+        // redraw the menu bar
+        for hwnd in self.hwnds.borrow().keys() {
+            redraw_menu_bar(*hwnd);
+        }
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) -> crate::Result<()> {
+        for child in self.children.drain(..) {
+            remove_from_parents(&child, self.hmenu, self.hpopupmenu);
+        }
+
+        // redraw the menu bar
+        for hwnd in self.hwnds.borrow().keys() {
+            redraw_menu_bar(*hwnd);
+        }
+
+        Ok(())
+    }
+
+    pub fn item_at(&self, position: usize) -> Option<MenuItemKind> {
+        self.children
+            .get(position)
+            .map(|c| c.borrow().kind(c.clone()))
+    }
+
     pub fn items(&self) -> Vec<MenuItemKind> {
         self.children
             .iter()
@@ -428,7 +461,7 @@ impl Menu {
         let rc = show_context_menu(hwnd as _, self.hpopupmenu, position);
         if let Some(item) = rc.and_then(|rc| self.find_by_id(rc)) {
             unsafe {
-                menu_selected(hwnd as _, &mut item.borrow_mut());
+                menu_selected(hwnd as _, &item);
             }
             return true;
         }
@@ -455,6 +488,7 @@ pub(crate) struct MenuChild {
     // shared fields between submenus and menu items
     item_type: MenuItemType,
     text: String,
+    text_key: Option<String>,
     enabled: bool,
     parents_hemnu: Vec<ParentMenu>,
     root_menu_haccel_stores: HashMap<u32, Rc<RefCell<AccelWrapper>>>,
@@ -466,6 +500,7 @@ pub(crate) struct MenuChild {
 
     // predefined menu item fields
     predefined_item_type: Option<PredefinedMenuItemType>,
+    action_handler: Option<ActionHandler>,
 
     // check menu item fields
     checked: bool,
@@ -477,6 +512,7 @@ pub(crate) struct MenuChild {
     hmenu: HMENU,
     hpopupmenu: HMENU,
     pub children: Option<Vec<Rc<RefCell<MenuChild>>>>,
+    pub groups: Option<MenuGroups>,
 }
 
 impl Drop for MenuChild {
@@ -490,7 +526,7 @@ impl Drop for MenuChild {
 
         if self.accelerator.is_some() {
             for store in self.root_menu_haccel_stores.values() {
-                AccelAction::remove(&mut store.borrow_mut(), self.internal_id)
+                AccelAction::remove(store, self.internal_id)
             }
         }
     }
@@ -518,6 +554,9 @@ impl MenuChild {
             icon: None,
             checked: false,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             hmenu: std::ptr::null_mut(),
             hpopupmenu: std::ptr::null_mut(),
         }
@@ -531,6 +570,9 @@ impl MenuChild {
             enabled,
             parents_hemnu: Vec::new(),
             children: Some(Vec::new()),
+            groups: Some(MenuGroups::default()),
+            text_key: None,
+            action_handler: None,
             hmenu: unsafe { CreateMenu() },
             internal_id,
             id: id.unwrap_or_else(|| MenuId::new(internal_id.to_string())),
@@ -543,7 +585,11 @@ impl MenuChild {
         }
     }
 
-    pub fn new_predefined(item_type: PredefinedMenuItemType, text: Option<String>) -> Self {
+    pub fn new_predefined(
+        item_type: PredefinedMenuItemType,
+        text: Option<String>,
+        accelerator: Option<Accelerator>,
+    ) -> Self {
         let internal_id = COUNTER.next();
         Self {
             item_type: MenuItemType::Predefined,
@@ -552,12 +598,15 @@ impl MenuChild {
             parents_hemnu: Vec::new(),
             internal_id,
             id: MenuId::new(internal_id.to_string()),
-            accelerator: item_type.accelerator(),
+            accelerator,
             predefined_item_type: Some(item_type),
             root_menu_haccel_stores: HashMap::new(),
             icon: None,
             checked: false,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             hmenu: std::ptr::null_mut(),
             hpopupmenu: std::ptr::null_mut(),
         }
@@ -584,6 +633,9 @@ impl MenuChild {
             predefined_item_type: None,
             icon: None,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             hmenu: std::ptr::null_mut(),
             hpopupmenu: std::ptr::null_mut(),
         }
@@ -610,6 +662,9 @@ impl MenuChild {
             predefined_item_type: None,
             checked: false,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             hmenu: std::ptr::null_mut(),
             hpopupmenu: std::ptr::null_mut(),
         }
@@ -636,6 +691,9 @@ impl MenuChild {
             icon: None,
             checked: false,
             children: None,
+            groups: None,
+            text_key: None,
+            action_handler: None,
             hmenu: std::ptr::null_mut(),
             hpopupmenu: std::ptr::null_mut(),
         }
@@ -652,6 +710,18 @@ impl MenuChild {
         &self.id
     }
 
+    pub fn accelerator(&self) -> Option<Accelerator> {
+        self.accelerator
+    }
+
+    pub fn predefined_item_type(&self) -> Option<PredefinedMenuItemType> {
+        self.predefined_item_type.clone()
+    }
+
+    pub fn icon(&self) -> Option<Icon> {
+        self.icon.clone()
+    }
+
     pub fn internal_id(&self) -> u32 {
         match self.item_type() {
             MenuItemType::Submenu => self.hmenu as u32,
@@ -659,6 +729,14 @@ impl MenuChild {
         }
     }
 
+    pub fn text_key(&self) -> Option<String> {
+        self.text_key.clone()
+    }
+
+    pub fn set_text_key(&mut self, key: Option<String>) {
+        self.text_key = key;
+    }
+
     pub fn text(&self) -> String {
         self.parents_hemnu
             .first()
@@ -701,7 +779,7 @@ impl MenuChild {
 
             if let Some(menu_bars) = menu_bars {
                 for hwnd in menu_bars.borrow().keys() {
-                    unsafe { DrawMenuBar(*hwnd as _) };
+                    redraw_menu_bar(*hwnd);
                 }
             }
         }
@@ -730,7 +808,7 @@ impl MenuChild {
 
             if let Some(menu_bars) = menu_bars {
                 for hwnd in menu_bars.borrow().keys() {
-                    unsafe { DrawMenuBar(*hwnd as _) };
+                    redraw_menu_bar(*hwnd);
                 }
             };
         }
@@ -741,11 +819,10 @@ impl MenuChild {
         self.set_text(&self.text.clone());
 
         for store in self.root_menu_haccel_stores.values() {
-            let mut store = store.borrow_mut();
             if let Some(accelerator) = self.accelerator {
-                AccelAction::add(&mut store, self.internal_id, &accelerator)?
+                AccelAction::add(store, self.internal_id, &accelerator)?
             } else {
-                AccelAction::remove(&mut store, self.internal_id)
+                AccelAction::remove(store, self.internal_id)
             }
         }
 
@@ -753,6 +830,17 @@ impl MenuChild {
     }
 }
 
+/// PredefinedMenuItem methods
+impl MenuChild {
+    pub fn action_handler(&self) -> Option<ActionHandler> {
+        self.action_handler.clone()
+    }
+
+    pub fn set_action_handler(&mut self, handler: Option<ActionHandler>) {
+        self.action_handler = handler;
+    }
+}
+
 /// CheckMenuItem methods
 impl MenuChild {
     pub fn is_checked(&self) -> bool {
@@ -780,7 +868,7 @@ impl MenuChild {
 
             if let Some(menu_bars) = menu_bars {
                 for hwnd in menu_bars.borrow().keys() {
-                    unsafe { DrawMenuBar(*hwnd as _) };
+                    redraw_menu_bar(*hwnd);
                 }
             };
         }
@@ -801,7 +889,7 @@ impl MenuChild {
 
             if let Some(menu_bars) = menu_bars {
                 for hwnd in menu_bars.borrow().keys() {
-                    unsafe { DrawMenuBar(*hwnd as _) };
+                    redraw_menu_bar(*hwnd);
                 }
             };
         }
@@ -839,8 +927,7 @@ impl MenuChild {
                 text.push_str(&accel_str);
 
                 for root_menu in self.root_menu_haccel_stores.values() {
-                    let mut haccel = root_menu.borrow_mut();
-                    AccelAction::add(&mut haccel, child_.internal_id(), accelerator)?;
+                    AccelAction::add(root_menu, child_.internal_id(), accelerator)?;
                 }
             }
 
@@ -942,6 +1029,33 @@ impl MenuChild {
         Ok(())
     }
 
+    pub fn move_item(&mut self, from: usize, to: usize) -> crate::Result<()> {
+        move_element(self.children.as_mut().unwrap(), from, to)?;
+
+        unsafe {
+            move_menu_item(self.hmenu, from, to);
+            move_menu_item(self.hpopupmenu, from, to);
+        }
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) -> crate::Result<()> {
+        for child in self.children.as_mut().unwrap().drain(..) {
+            remove_from_parents(&child, self.hmenu, self.hpopupmenu);
+        }
+
+        Ok(())
+    }
+
+    pub fn item_at(&self, position: usize) -> Option<MenuItemKind> {
+        self.children
+            .as_ref()
+            .unwrap()
+            .get(position)
+            .map(|c| c.borrow().kind(c.clone()))
+    }
+
     pub fn items(&self) -> Vec<MenuItemKind> {
         self.children
             .as_ref()
@@ -959,7 +1073,7 @@ impl MenuChild {
         let rc = show_context_menu(hwnd as _, self.hpopupmenu, position);
         if let Some(item) = rc.and_then(|rc| self.find_by_id(rc)) {
             unsafe {
-                menu_selected(hwnd as _, &mut item.borrow_mut());
+                menu_selected(hwnd as _, &item);
             }
             return true;
         }
@@ -1047,31 +1161,81 @@ struct AccelAction;
 
 impl AccelAction {
     fn add(
-        haccel_store: &mut RefMut<AccelWrapper>,
+        haccel_store: &Rc<RefCell<AccelWrapper>>,
         id: u32,
         accelerator: &Accelerator,
     ) -> crate::Result<()> {
         let accel = accelerator.to_accel(id as _)?;
-        haccel_store.1.insert(id, Accel(accel));
+        haccel_store.borrow_mut().1.insert(id, Accel(accel));
         Self::update_store(haccel_store);
         Ok(())
     }
 
-    fn remove(haccel_store: &mut RefMut<AccelWrapper>, id: u32) {
-        haccel_store.1.remove(&id);
+    fn remove(haccel_store: &Rc<RefCell<AccelWrapper>>, id: u32) {
+        haccel_store.borrow_mut().1.remove(&id);
         Self::update_store(haccel_store)
     }
 
-    fn update_store(haccel_store: &mut RefMut<AccelWrapper>) {
-        unsafe {
-            DestroyAcceleratorTable(haccel_store.0);
-            let len = haccel_store.1.len();
-            let accels = haccel_store.1.values().map(|i| i.0).collect::<Vec<_>>();
-            haccel_store.0 = CreateAcceleratorTableW(accels.as_ptr(), len as _);
+    // the table is rebuilt from scratch, so it is only done once at the end of a batch
+    fn update_store(haccel_store: &Rc<RefCell<AccelWrapper>>) {
+        let haccel_store = haccel_store.clone();
+        defer((Rc::as_ptr(&haccel_store) as usize, "haccel"), move || {
+            let mut haccel_store = haccel_store.borrow_mut();
+            unsafe {
+                DestroyAcceleratorTable(haccel_store.0);
+                let len = haccel_store.1.len();
+                let accels = haccel_store.1.values().map(|i| i.0).collect::<Vec<_>>();
+                haccel_store.0 = CreateAcceleratorTableW(accels.as_ptr(), len as _);
+            }
+        });
+    }
+}
+
+fn redraw_menu_bar(hwnd: Hwnd) {
+    defer((hwnd as usize, "redraw"), move || unsafe {
+        DrawMenuBar(hwnd as _);
+    });
+}
+
+/// Removes the native item of `child` from `hmenu` and `hpopupmenu`, without destroying it.
+fn remove_from_parents(child: &Rc<RefCell<MenuChild>>, hmenu: HMENU, hpopupmenu: HMENU) {
+    let mut child = child.borrow_mut();
+    let id = child.internal_id();
+    unsafe {
+        RemoveMenu(hmenu, id, MF_BYCOMMAND);
+        RemoveMenu(hpopupmenu, id, MF_BYCOMMAND);
+    }
+    for parent in [hmenu, hpopupmenu] {
+        if let Some(index) = child.parents_hemnu.iter().position(|&(h, _)| h == parent) {
+            child.parents_hemnu.remove(index);
         }
     }
 }
 
+/// Moves the item at position `from` to position `to` in `hmenu`,
+/// keeping its id, state, text, bitmap and submenu.
+unsafe fn move_menu_item(hmenu: HMENU, from: usize, to: usize) {
+    let mut info: MENUITEMINFOW = std::mem::zeroed();
+    info.cbSize = std::mem::size_of::<MENUITEMINFOW>() as _;
+    info.fMask =
+        MIIM_FTYPE | MIIM_STATE | MIIM_ID | MIIM_SUBMENU | MIIM_STRING | MIIM_BITMAP | MIIM_DATA;
+
+    // first call to get the length of the text
+    if GetMenuItemInfoW(hmenu, from as _, true.into(), &mut info) == 0 {
+        return;
+    }
+    let mut text = vec![0u16; info.cch as usize + 1];
+    info.cch += 1;
+    info.dwTypeData = text.as_mut_ptr();
+    if GetMenuItemInfoW(hmenu, from as _, true.into(), &mut info) == 0 {
+        return;
+    }
+
+    // removing doesn't destroy the submenu of the item, unlike `DeleteMenu`
+    RemoveMenu(hmenu, from as _, MF_BYPOSITION);
+    InsertMenuItemW(hmenu, to as _, true.into(), &info);
+}
+
 fn create_icon_item_info(hbitmap: HBITMAP) -> MENUITEMINFOW {
     let mut info: MENUITEMINFOW = unsafe { std::mem::zeroed() };
     info.cbSize = std::mem::size_of::<MENUITEMINFOW>() as _;
@@ -1131,7 +1295,7 @@ unsafe extern "system" fn menu_subclass_proc(
             };
 
             if let Some(item) = item {
-                menu_selected(hwnd, &mut item.borrow_mut());
+                menu_selected(hwnd, &item);
                 0
             } else {
                 DefSubclassProc(hwnd as _, msg, wparam, lparam)
@@ -1176,7 +1340,22 @@ unsafe extern "system" fn menu_subclass_proc(
     }
 }
 
-unsafe fn menu_selected(hwnd: windows_sys::Win32::Foundation::HWND, item: &mut MenuChild) {
+unsafe fn menu_selected(hwnd: windows_sys::Win32::Foundation::HWND, item: &RefCell<MenuChild>) {
+    // run the handler replacing the native action without borrowing the item,
+    // so the handler can update it
+    let action = {
+        let item = item.borrow();
+        item.predefined_item_type
+            .clone()
+            .map(|item_type| (item.action_handler.clone(), item_type, item.id.clone()))
+    };
+    if let Some((handler, item_type, id)) = action {
+        if ActionHandler::run(handler.as_ref(), &item_type, &id) {
+            return;
+        }
+    }
+
+    let item = &mut *item.borrow_mut();
     let (mut dispatch, mut menu_id) = (true, None);
 
     {
@@ -1200,6 +1379,7 @@ unsafe fn menu_selected(hwnd: windows_sys::Win32::Foundation::HWND, item: &mut M
                         PredefinedMenuItemType::SelectAll => {
                             execute_edit_command(EditCommand::SelectAll)
                         }
+                        PredefinedMenuItemType::Delete => execute_edit_command(EditCommand::Delete),
                         PredefinedMenuItemType::Separator => {}
                         PredefinedMenuItemType::Minimize => {
                             ShowWindow(hwnd, SW_MINIMIZE);
@@ -1219,6 +1399,17 @@ unsafe fn menu_selected(hwnd: windows_sys::Win32::Foundation::HWND, item: &mut M
                         PredefinedMenuItemType::About(Some(ref metadata)) => {
                             show_about_dialog(hwnd as _, metadata)
                         }
+                        PredefinedMenuItemType::Find
+                        | PredefinedMenuItemType::FindNext
+                        | PredefinedMenuItemType::Preferences
+                        | PredefinedMenuItemType::Help
+                        | PredefinedMenuItemType::NewWindow
+                        | PredefinedMenuItemType::ZoomIn
+                        | PredefinedMenuItemType::ZoomOut
+                        | PredefinedMenuItemType::ZoomReset => {
+                            dispatch = true;
+                            menu_id.replace(item.id.clone());
+                        }
 
                         _ => {}
                     }
@@ -1250,6 +1441,44 @@ enum EditCommand {
     Cut,
     Paste,
     SelectAll,
+    Delete,
+}
+
+impl PredefinedMenuItemType {
+    /// Returns `label`, or the built-in English text, with `app_name` after the text of About items.
+    pub(crate) fn platform_text(&self, label: Option<String>, app_name: Option<&str>) -> String {
+        let text = label.unwrap_or_else(|| self.text().to_string());
+        match (self, app_name) {
+            (PredefinedMenuItemType::About(_), Some(app_name)) => format!("{text} {app_name}"),
+            _ => text,
+        }
+    }
+
+    pub(crate) fn is_platform_supported(&self) -> bool {
+        matches!(
+            self,
+            PredefinedMenuItemType::Separator
+                | PredefinedMenuItemType::Copy
+                | PredefinedMenuItemType::Cut
+                | PredefinedMenuItemType::Paste
+                | PredefinedMenuItemType::SelectAll
+                | PredefinedMenuItemType::Minimize
+                | PredefinedMenuItemType::Maximize
+                | PredefinedMenuItemType::Hide
+                | PredefinedMenuItemType::CloseWindow
+                | PredefinedMenuItemType::Quit
+                | PredefinedMenuItemType::About(_)
+                | PredefinedMenuItemType::Delete
+                | PredefinedMenuItemType::Find
+                | PredefinedMenuItemType::FindNext
+                | PredefinedMenuItemType::Preferences
+                | PredefinedMenuItemType::Help
+                | PredefinedMenuItemType::NewWindow
+                | PredefinedMenuItemType::ZoomIn
+                | PredefinedMenuItemType::ZoomOut
+                | PredefinedMenuItemType::ZoomReset
+        )
+    }
 }
 
 fn execute_edit_command(command: EditCommand) {
@@ -1258,27 +1487,32 @@ fn execute_edit_command(command: EditCommand) {
         EditCommand::Cut => 0x58,       // x
         EditCommand::Paste => 0x56,     // v
         EditCommand::SelectAll => 0x41, // a
+        EditCommand::Delete => VK_DELETE,
     };
 
-    unsafe {
-        let mut inputs: [INPUT; 4] = std::mem::zeroed();
-        inputs[0].r#type = INPUT_KEYBOARD;
-        inputs[0].Anonymous.ki.wVk = VK_CONTROL;
-        inputs[2].Anonymous.ki.dwFlags = 0;
-
-        inputs[1].r#type = INPUT_KEYBOARD;
-        inputs[1].Anonymous.ki.wVk = key;
-        inputs[2].Anonymous.ki.dwFlags = 0;
-
-        inputs[2].r#type = INPUT_KEYBOARD;
-        inputs[2].Anonymous.ki.wVk = key;
-        inputs[2].Anonymous.ki.dwFlags = KEYEVENTF_KEYUP;
+    let mut keys = vec![(key, 0), (key, KEYEVENTF_KEYUP)];
+    if !matches!(command, EditCommand::Delete) {
+        keys.insert(0, (VK_CONTROL, 0));
+        keys.push((VK_CONTROL, KEYEVENTF_KEYUP));
+    }
 
-        inputs[3].r#type = INPUT_KEYBOARD;
-        inputs[3].Anonymous.ki.wVk = VK_CONTROL;
-        inputs[3].Anonymous.ki.dwFlags = KEYEVENTF_KEYUP;
+    unsafe {
+        let inputs = keys
+            .into_iter()
+            .map(|(key, flags)| {
+                let mut input: INPUT = std::mem::zeroed();
+                input.r#type = INPUT_KEYBOARD;
+                input.Anonymous.ki.wVk = key;
+                input.Anonymous.ki.dwFlags = flags;
+                input
+            })
+            .collect::<Vec<_>>();
 
-        SendInput(4, &inputs as *const _, std::mem::size_of::<INPUT>() as _);
+        SendInput(
+            inputs.len() as _,
+            inputs.as_ptr(),
+            std::mem::size_of::<INPUT>() as _,
+        );
     }
 }
 
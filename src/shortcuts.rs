@@ -0,0 +1,266 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+use crate::{accelerator::Platform, Command};
+
+/// The output format of [`Menu::export_shortcuts`](crate::Menu::export_shortcuts).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShortcutFormat {
+    /// A Markdown table per group, under a `##` heading.
+    Markdown,
+    /// An HTML table per group, under an `<h2>` heading.
+    Html,
+    /// A JSON array of groups, each with a `group` name and a list of `shortcuts`
+    /// with the `id`, `path` and `accelerator` of the items.
+    Json,
+}
+
+/// Commands with an accelerator, grouped by their top-level submenu.
+///
+/// Items that are not in a submenu have an empty group, which comes first.
+fn groups(commands: Vec<Command>) -> Vec<(String, Vec<Command>)> {
+    let mut groups: Vec<(String, Vec<Command>)> = Vec::new();
+    for command in commands.into_iter().filter(|c| c.accelerator.is_some()) {
+        let group = match command.path.len() {
+            1 => String::new(),
+            _ => command.path[0].clone(),
+        };
+        match groups.iter_mut().find(|(g, _)| *g == group) {
+            Some((_, commands)) => commands.push(command),
+            None if group.is_empty() => groups.insert(0, (group, vec![command])),
+            None => groups.push((group, vec![command])),
+        }
+    }
+    groups
+}
+
+/// Returns the path of `command` inside its group.
+fn name(command: &Command) -> String {
+    let skip = usize::from(command.path.len() > 1);
+    command.path[skip..].join(" › ")
+}
+
+pub(crate) fn export(commands: Vec<Command>, format: ShortcutFormat, platform: Platform) -> String {
+    let label = |command: &Command| {
+        command
+            .accelerator
+            .map(|a| a.label_for(platform))
+            .unwrap_or_default()
+    };
+    let groups = groups(commands);
+    let mut out = String::new();
+    match format {
+        ShortcutFormat::Markdown => {
+            let escape = |s: &str| {
+                let mut out = String::with_capacity(s.len());
+                for c in s.chars() {
+                    if matches!(c, '\\' | '|' | '<' | '*' | '_' | '`') {
+                        out.push('\\');
+                    }
+                    out.push(c);
+                }
+                out
+            };
+            for (group, commands) in groups {
+                if !out.is_empty() {
+                    out.push('\n');
+                }
+                if !group.is_empty() {
+                    let _ = writeln!(out, "## {}\n", escape(&group));
+                }
+                out.push_str("| Command | Shortcut |\n| --- | --- |\n");
+                for command in commands {
+                    let label = label(&command);
+                    // a code span containing a backquote needs longer delimiters,
+                    // and a space between them and a backquote at either end
+                    let code = if label.contains('`') { "``" } else { "`" };
+                    let pad = if label.starts_with('`') || label.ends_with('`') {
+                        " "
+                    } else {
+                        ""
+                    };
+                    let _ = writeln!(
+                        out,
+                        "| {} | {code}{pad}{}{pad}{code} |",
+                        escape(&name(&command)),
+                        label.replace('|', "\\|")
+                    );
+                }
+            }
+        }
+        ShortcutFormat::Html => {
+            for (group, commands) in groups {
+                if !group.is_empty() {
+                    let _ = writeln!(out, "<h2>{}</h2>", escape_html(&group));
+                }
+                out.push_str("<table>\n  <thead><tr><th>Command</th><th>Shortcut</th></tr></thead>\n  <tbody>\n");
+                for command in commands {
+                    let _ = writeln!(
+                        out,
+                        "    <tr><td>{}</td><td><kbd>{}</kbd></td></tr>",
+                        escape_html(&name(&command)),
+                        escape_html(&label(&command))
+                    );
+                }
+                out.push_str("  </tbody>\n</table>\n");
+            }
+        }
+        ShortcutFormat::Json => {
+            out.push('[');
+            for (i, (group, commands)) in groups.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                let _ = write!(
+                    out,
+                    "\n  {{\n    \"group\": {},\n    \"shortcuts\": [",
+                    json_string(&group)
+                );
+                for (j, command) in commands.iter().enumerate() {
+                    if j > 0 {
+                        out.push(',');
+                    }
+                    let path = command
+                        .path
+                        .iter()
+                        .map(|p| json_string(p))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let _ = write!(
+                        out,
+                        "\n      {{ \"id\": {}, \"path\": [{}], \"accelerator\": {} }}",
+                        json_string(&command.id.0),
+                        path,
+                        json_string(&label(command))
+                    );
+                }
+                out.push_str("\n    ]\n  }");
+            }
+            out.push_str("\n]\n");
+        }
+    }
+    out
+}
+
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::ShortcutFormat;
+    use crate::{accelerator::Platform, Menu, MenuItem, Submenu};
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn export_shortcuts() {
+        let item = |id: &str, text: &str, accelerator: Option<&str>| {
+            MenuItem::with_id(id, text, true, accelerator.map(|a| a.parse().unwrap()))
+        };
+        let find = Submenu::with_items(
+            "&Find",
+            true,
+            &[&item("replace", "&Replace <All>", Some("Alt+KeyR"))],
+        )
+        .unwrap();
+        let edit = Submenu::with_items(
+            "&Edit",
+            true,
+            &[
+                &item("copy", "Copy | Paste", Some("Shift+KeyC")),
+                &item("none", "No Shortcut", None),
+                &find,
+            ],
+        )
+        .unwrap();
+        let menu = Menu::with_items(&[&edit]).unwrap();
+        #[cfg(not(target_os = "macos"))]
+        menu.append(&item("help", "\"Help\"", Some("F1"))).unwrap();
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            assert_eq!(
+                menu.export_shortcuts(ShortcutFormat::Markdown),
+                "| Command | Shortcut |\n| --- | --- |\n| \"Help\" | `F1` |\n\n\
+                 ## Edit\n\n| Command | Shortcut |\n| --- | --- |\n\
+                 | Copy \\| Paste | `Shift+C` |\n| Find › Replace \\<All> | `Alt+R` |\n"
+            );
+
+            let html = menu.export_shortcuts(ShortcutFormat::Html);
+            assert!(html.contains("<h2>Edit</h2>"));
+            assert!(html
+                .contains("<tr><td>Find › Replace &lt;All&gt;</td><td><kbd>Alt+R</kbd></td></tr>"));
+
+            let json = menu.export_shortcuts(ShortcutFormat::Json);
+            assert!(json.starts_with("[\n  {\n    \"group\": \"\",\n"));
+            assert!(json.contains(
+                "{ \"id\": \"help\", \"path\": [\"\\\"Help\\\"\"], \"accelerator\": \"F1\" }"
+            ));
+            assert!(json.contains("\"path\": [\"Edit\", \"Find\", \"Replace <All>\"]"));
+        }
+
+        assert!(!menu
+            .export_shortcuts(ShortcutFormat::Markdown)
+            .contains("No Shortcut"));
+
+        let format = Submenu::with_items(
+            "F`ormat",
+            true,
+            &[&item("bold", "*Bold* _Text_", Some("Super+Alt+KeyB"))],
+        )
+        .unwrap();
+        let menu = Menu::with_items(&[&format]).unwrap();
+        assert_eq!(
+            menu.export_shortcuts_for(ShortcutFormat::Markdown, Platform::MacOS),
+            "## F\\`ormat\n\n| Command | Shortcut |\n| --- | --- |\n\
+             | \\*Bold\\* \\_Text\\_ | `⌥⌘B` |\n"
+        );
+        assert!(menu
+            .export_shortcuts_for(ShortcutFormat::Json, Platform::Windows)
+            .contains("\"accelerator\": \"Alt+Super+B\""));
+
+        let menu =
+            Menu::with_items(&[&item("terminal", "Terminal", Some("Control+Backquote"))]).unwrap();
+        assert!(menu
+            .export_shortcuts_for(ShortcutFormat::Markdown, Platform::Windows)
+            .contains("| Terminal | ``Ctrl+` `` |\n"));
+        assert!(menu
+            .export_shortcuts_for(ShortcutFormat::Markdown, Platform::MacOS)
+            .contains("| Terminal | ``⌃` `` |\n"));
+    }
+}
@@ -0,0 +1,628 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::mem;
+
+use crate::{
+    accelerator::Accelerator, items::PredefinedMenuItemType, util::Container, CheckMenuItem, Icon,
+    IconMenuItem, IsMenuItem, Menu, MenuId, MenuItem, MenuItemKind, PredefinedMenuItem, Submenu,
+};
+
+/// A declarative description of a [`Menu`].
+///
+/// It can be turned into a new [`Menu`] using [`MenuSpec::build`] or applied
+/// to an existing one using [`Menu::reconcile`].
+#[derive(Debug, Clone, Default)]
+pub struct MenuSpec {
+    /// The items of the menu, in order.
+    pub items: Vec<MenuItemSpec>,
+}
+
+impl MenuSpec {
+    /// Creates a new menu description with the given `items`.
+    pub fn new(items: Vec<MenuItemSpec>) -> Self {
+        Self { items }
+    }
+
+    /// Creates a new [`Menu`] that matches this description.
+    pub fn build(&self) -> crate::Result<Menu> {
+        let menu = Menu::new();
+        for item in &self.items {
+            menu.append(item.build()?.as_ref())?;
+        }
+        Ok(menu)
+    }
+}
+
+/// A declarative description of an item inside a [`MenuSpec`].
+///
+/// Items are matched against the live menu by their [`MenuId`], except for
+/// predefined items which are matched by their type and order of appearance.
+///
+/// `text_key` is the translation key of the item, see [`MenuItem::set_text_key`].
+/// When a live item already has the same translation key, its text is left as is,
+/// so a menu localized with [`Menu::relocalize`] stays localized after a reconcile.
+/// Items with a new translation key get `text` and need to be localized again.
+///
+/// [`Menu::relocalize`]: crate::Menu::relocalize
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+pub enum MenuItemSpec {
+    /// Describes a [`MenuItem`].
+    MenuItem {
+        id: MenuId,
+        text: String,
+        enabled: bool,
+        accelerator: Option<Accelerator>,
+        text_key: Option<String>,
+    },
+    /// Describes a [`CheckMenuItem`].
+    Check {
+        id: MenuId,
+        text: String,
+        enabled: bool,
+        checked: bool,
+        accelerator: Option<Accelerator>,
+        text_key: Option<String>,
+    },
+    /// Describes an [`IconMenuItem`].
+    ///
+    /// Icons can't be compared, so the icon of a live item is set again every time it is reconciled.
+    Icon {
+        id: MenuId,
+        text: String,
+        enabled: bool,
+        icon: Option<Icon>,
+        accelerator: Option<Accelerator>,
+        text_key: Option<String>,
+    },
+    /// Describes a [`Submenu`] and its items.
+    Submenu {
+        id: MenuId,
+        text: String,
+        enabled: bool,
+        items: Vec<MenuItemSpec>,
+        text_key: Option<String>,
+    },
+    /// Describes a [`PredefinedMenuItem`], if `text` is `None`, the default text is used,
+    /// see [`PredefinedMenuItem::set_label_provider`].
+    Predefined {
+        item_type: PredefinedMenuItemType,
+        text: Option<String>,
+        text_key: Option<String>,
+    },
+}
+
+impl MenuItemSpec {
+    /// Describes a [`MenuItem`], see [`MenuItem::with_id`].
+    pub fn menu_item<I: Into<MenuId>, S: AsRef<str>>(
+        id: I,
+        text: S,
+        enabled: bool,
+        accelerator: Option<Accelerator>,
+    ) -> Self {
+        Self::MenuItem {
+            id: id.into(),
+            text: text.as_ref().to_string(),
+            enabled,
+            accelerator,
+            text_key: None,
+        }
+    }
+
+    /// Describes a [`CheckMenuItem`], see [`CheckMenuItem::with_id`].
+    pub fn check<I: Into<MenuId>, S: AsRef<str>>(
+        id: I,
+        text: S,
+        enabled: bool,
+        checked: bool,
+        accelerator: Option<Accelerator>,
+    ) -> Self {
+        Self::Check {
+            id: id.into(),
+            text: text.as_ref().to_string(),
+            enabled,
+            checked,
+            accelerator,
+            text_key: None,
+        }
+    }
+
+    /// Describes an [`IconMenuItem`], see [`IconMenuItem::with_id`].
+    pub fn icon<I: Into<MenuId>, S: AsRef<str>>(
+        id: I,
+        text: S,
+        enabled: bool,
+        icon: Option<Icon>,
+        accelerator: Option<Accelerator>,
+    ) -> Self {
+        Self::Icon {
+            id: id.into(),
+            text: text.as_ref().to_string(),
+            enabled,
+            icon,
+            accelerator,
+            text_key: None,
+        }
+    }
+
+    /// Describes a [`Submenu`], see [`Submenu::with_id`].
+    pub fn submenu<I: Into<MenuId>, S: AsRef<str>>(
+        id: I,
+        text: S,
+        enabled: bool,
+        items: Vec<MenuItemSpec>,
+    ) -> Self {
+        Self::Submenu {
+            id: id.into(),
+            text: text.as_ref().to_string(),
+            enabled,
+            items,
+            text_key: None,
+        }
+    }
+
+    /// Describes a [`PredefinedMenuItem`] of the given type.
+    pub fn predefined(item_type: PredefinedMenuItemType, text: Option<&str>) -> Self {
+        Self::Predefined {
+            item_type,
+            text: text.map(|t| t.to_string()),
+            text_key: None,
+        }
+    }
+
+    /// Describes a separator, see [`PredefinedMenuItem::separator`].
+    pub fn separator() -> Self {
+        Self::predefined(PredefinedMenuItemType::Separator, None)
+    }
+
+    /// Set the translation key of the described item, see [`MenuItem::set_text_key`].
+    pub fn text_key<S: Into<String>>(mut self, key: S) -> Self {
+        match &mut self {
+            MenuItemSpec::MenuItem { text_key, .. }
+            | MenuItemSpec::Check { text_key, .. }
+            | MenuItemSpec::Icon { text_key, .. }
+            | MenuItemSpec::Submenu { text_key, .. }
+            | MenuItemSpec::Predefined { text_key, .. } => {
+                text_key.replace(key.into());
+            }
+        }
+        self
+    }
+
+    fn translation_key(&self) -> Option<&str> {
+        match self {
+            MenuItemSpec::MenuItem { text_key, .. }
+            | MenuItemSpec::Check { text_key, .. }
+            | MenuItemSpec::Icon { text_key, .. }
+            | MenuItemSpec::Submenu { text_key, .. }
+            | MenuItemSpec::Predefined { text_key, .. } => text_key.as_deref(),
+        }
+    }
+
+    /// Creates a new menu item that matches this description.
+    pub fn build(&self) -> crate::Result<MenuItemKind> {
+        let item = match self {
+            MenuItemSpec::MenuItem {
+                id,
+                text,
+                enabled,
+                accelerator,
+                ..
+            } => MenuItem::with_id(id.clone(), text, *enabled, *accelerator).kind(),
+            MenuItemSpec::Check {
+                id,
+                text,
+                enabled,
+                checked,
+                accelerator,
+                ..
+            } => CheckMenuItem::with_id(id.clone(), text, *enabled, *checked, *accelerator).kind(),
+            MenuItemSpec::Icon {
+                id,
+                text,
+                enabled,
+                icon,
+                accelerator,
+                ..
+            } => {
+                IconMenuItem::with_id(id.clone(), text, *enabled, icon.clone(), *accelerator).kind()
+            }
+            MenuItemSpec::Submenu {
+                id,
+                text,
+                enabled,
+                items,
+                ..
+            } => {
+                let submenu = Submenu::with_id(id.clone(), text, *enabled);
+                for item in items {
+                    submenu.append(item.build()?.as_ref())?;
+                }
+                submenu.kind()
+            }
+            MenuItemSpec::Predefined {
+                item_type, text, ..
+            } => PredefinedMenuItem::new(item_type.clone(), text.as_deref()).kind(),
+        };
+        set_text_key(&item, self.translation_key());
+        Ok(item)
+    }
+
+    fn is_same_kind(&self, item: &MenuItemKind) -> bool {
+        match (self, item) {
+            (MenuItemSpec::MenuItem { .. }, MenuItemKind::MenuItem(_))
+            | (MenuItemSpec::Check { .. }, MenuItemKind::Check(_))
+            | (MenuItemSpec::Icon { .. }, MenuItemKind::Icon(_))
+            | (MenuItemSpec::Submenu { .. }, MenuItemKind::Submenu(_)) => true,
+            // an about item is rebuilt when its metadata changes
+            (MenuItemSpec::Predefined { item_type, .. }, MenuItemKind::Predefined(item)) => {
+                match (item_type, &item.predefined_item_type()) {
+                    (
+                        PredefinedMenuItemType::About(Some(a)),
+                        PredefinedMenuItemType::About(Some(b)),
+                    ) => a.is_same(b),
+                    (PredefinedMenuItemType::About(a), PredefinedMenuItemType::About(b)) => {
+                        a.is_none() && b.is_none()
+                    }
+                    _ => true,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Applies this description to `item`, which must be of the same kind.
+    fn update(&self, item: &MenuItemKind) -> crate::Result<()> {
+        // the text of an item with the same translation key may have been localized,
+        // so it is left to `Menu::relocalize`
+        let localized = match self.translation_key() {
+            Some(key) => item.child().text_key().as_deref() == Some(key),
+            None => false,
+        };
+        if !localized && item.child().text_key().as_deref() != self.translation_key() {
+            set_text_key(item, self.translation_key());
+        }
+
+        match (self, item) {
+            (
+                MenuItemSpec::MenuItem {
+                    text,
+                    enabled,
+                    accelerator,
+                    ..
+                },
+                MenuItemKind::MenuItem(item),
+            ) => {
+                if !localized && item.text() != *text {
+                    item.set_text(text);
+                }
+                if item.is_enabled() != *enabled {
+                    item.set_enabled(*enabled);
+                }
+                if item.inner.borrow().accelerator() != *accelerator {
+                    item.set_accelerator(*accelerator)?;
+                }
+            }
+            (
+                MenuItemSpec::Check {
+                    text,
+                    enabled,
+                    checked,
+                    accelerator,
+                    ..
+                },
+                MenuItemKind::Check(item),
+            ) => {
+                if !localized && item.text() != *text {
+                    item.set_text(text);
+                }
+                if item.is_enabled() != *enabled {
+                    item.set_enabled(*enabled);
+                }
+                if item.is_checked() != *checked {
+                    item.set_checked(*checked);
+                }
+                if item.inner.borrow().accelerator() != *accelerator {
+                    item.set_accelerator(*accelerator)?;
+                }
+            }
+            (
+                MenuItemSpec::Icon {
+                    text,
+                    enabled,
+                    icon,
+                    accelerator,
+                    ..
+                },
+                MenuItemKind::Icon(item),
+            ) => {
+                if !localized && item.text() != *text {
+                    item.set_text(text);
+                }
+                if item.is_enabled() != *enabled {
+                    item.set_enabled(*enabled);
+                }
+                let same_icon = match (item.inner.borrow().icon(), icon) {
+                    (Some(a), Some(b)) => a.inner == b.inner,
+                    (a, b) => a.is_none() && b.is_none(),
+                };
+                if !same_icon {
+                    item.set_icon(icon.clone());
+                }
+                if item.inner.borrow().accelerator() != *accelerator {
+                    item.set_accelerator(*accelerator)?;
+                }
+            }
+            (
+                MenuItemSpec::Submenu {
+                    text,
+                    enabled,
+                    items,
+                    ..
+                },
+                MenuItemKind::Submenu(item),
+            ) => {
+                if !localized && item.text() != *text {
+                    item.set_text(text);
+                }
+                if item.is_enabled() != *enabled {
+                    item.set_enabled(*enabled);
+                }
+                reconcile_items(Container::Submenu(item), items)?;
+            }
+            (MenuItemSpec::Predefined { text, .. }, MenuItemKind::Predefined(item))
+                if !localized =>
+            {
+                // the default text is kept up to date by the label provider
+                match text {
+                    Some(text) if item.text() != *text => item.set_text(text),
+                    Some(_) => {}
+                    None => item.reset_text(),
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+fn set_text_key(item: &MenuItemKind, key: Option<&str>) {
+    match item {
+        MenuItemKind::MenuItem(i) => i.set_text_key(key),
+        MenuItemKind::Submenu(i) => i.set_text_key(key),
+        MenuItemKind::Predefined(i) => i.set_text_key(key),
+        MenuItemKind::Check(i) => i.set_text_key(key),
+        MenuItemKind::Icon(i) => i.set_text_key(key),
+    }
+}
+
+/// The key used to match a described item with a live one.
+#[derive(PartialEq)]
+enum Key {
+    Id(MenuId),
+    /// Predefined items don't have stable ids, so they are keyed by
+    /// their type and the number of items of the same type before them.
+    Predefined(mem::Discriminant<PredefinedMenuItemType>, usize),
+}
+
+fn predefined_key(
+    item_type: &PredefinedMenuItemType,
+    seen: &mut Vec<mem::Discriminant<PredefinedMenuItemType>>,
+) -> Key {
+    let discriminant = mem::discriminant(item_type);
+    let nth = seen.iter().filter(|d| **d == discriminant).count();
+    seen.push(discriminant);
+    Key::Predefined(discriminant, nth)
+}
+
+fn spec_keys(specs: &[MenuItemSpec]) -> Vec<Key> {
+    let mut seen = Vec::new();
+    specs
+        .iter()
+        .map(|spec| match spec {
+            MenuItemSpec::MenuItem { id, .. }
+            | MenuItemSpec::Check { id, .. }
+            | MenuItemSpec::Icon { id, .. }
+            | MenuItemSpec::Submenu { id, .. } => Key::Id(id.clone()),
+            MenuItemSpec::Predefined { item_type, .. } => predefined_key(item_type, &mut seen),
+        })
+        .collect()
+}
+
+fn item_keys(items: Vec<MenuItemKind>) -> Vec<(Key, MenuItemKind)> {
+    let mut seen = Vec::new();
+    items
+        .into_iter()
+        .map(|item| {
+            let key = match &item {
+                MenuItemKind::Predefined(i) => predefined_key(&i.predefined_item_type(), &mut seen),
+                _ => Key::Id(item.id().clone()),
+            };
+            (key, item)
+        })
+        .collect()
+}
+
+/// Updates the items of `container` in place to match `specs`, reusing
+/// every live item that has a matching key and kind.
+pub(crate) fn reconcile_items(container: Container, specs: &[MenuItemSpec]) -> crate::Result<()> {
+    let keys = spec_keys(specs);
+
+    let mut current = Vec::new();
+    for (key, item) in item_keys(container.items()) {
+        let wanted = keys
+            .iter()
+            .zip(specs)
+            .any(|(k, spec)| *k == key && spec.is_same_kind(&item));
+        if wanted {
+            current.push((key, item));
+        } else {
+            container.remove(item.as_ref())?;
+        }
+    }
+
+    for (position, (key, spec)) in keys.into_iter().zip(specs).enumerate() {
+        let found = current
+            .iter()
+            .skip(position)
+            .position(|(k, item)| *k == key && spec.is_same_kind(item))
+            .map(|i| i + position);
+
+        match found {
+            Some(i) => {
+                if i != position {
+                    container.move_item(i, position)?;
+                    let entry = current.remove(i);
+                    current.insert(position, entry);
+                }
+                spec.update(&current[position].1)?;
+            }
+            None => {
+                let item = spec.build()?;
+                container.insert(item.as_ref(), position)?;
+                current.insert(position, (key, item));
+            }
+        }
+    }
+
+    // anything left is a duplicate that couldn't be matched
+    for (_, item) in current.drain(specs.len()..) {
+        container.remove(item.as_ref())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        AboutMetadata, BundledLabels, IconMenuItem, Menu, MenuId, MenuItemSpec, MenuSpec,
+        PredefinedMenuItem, PredefinedMenuItemType,
+    };
+
+    fn ids(menu: &Menu) -> Vec<MenuId> {
+        menu.items().iter().map(|i| i.id().clone()).collect()
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn reconcile_reuses_items() {
+        let spec = MenuSpec::new(vec![
+            MenuItemSpec::menu_item("new", "New", true, None),
+            MenuItemSpec::separator(),
+            MenuItemSpec::check("wrap", "Wrap", true, false, None),
+            MenuItemSpec::menu_item("quit", "Quit", true, None),
+        ]);
+        let menu = spec.build().unwrap();
+        let separator = menu.items()[1].id().clone();
+
+        let spec = MenuSpec::new(vec![
+            MenuItemSpec::check("wrap", "Word Wrap", true, true, None),
+            MenuItemSpec::menu_item("new", "New", false, None),
+            MenuItemSpec::separator(),
+            MenuItemSpec::menu_item("open", "Open", true, None),
+        ]);
+        menu.reconcile(&spec).unwrap();
+
+        let items = menu.items();
+        assert_eq!(
+            ids(&menu),
+            vec![
+                MenuId::new("wrap"),
+                MenuId::new("new"),
+                separator,
+                MenuId::new("open")
+            ]
+        );
+        let wrap = items[0].as_check_menuitem_unchecked();
+        assert_eq!(wrap.text(), "Word Wrap");
+        assert!(wrap.is_checked());
+        assert!(!items[1].as_menuitem_unchecked().is_enabled());
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn reconcile_replaces_items_of_another_kind() {
+        let menu = MenuSpec::new(vec![MenuItemSpec::submenu(
+            "edit",
+            "Edit",
+            true,
+            vec![MenuItemSpec::menu_item("find", "Find", true, None)],
+        )])
+        .build()
+        .unwrap();
+
+        menu.reconcile(&MenuSpec::new(vec![MenuItemSpec::submenu(
+            "edit",
+            "Edit",
+            true,
+            vec![MenuItemSpec::check("find", "Find", true, true, None)],
+        )]))
+        .unwrap();
+
+        let edit = menu.items()[0].as_submenu_unchecked().items();
+        assert_eq!(edit.len(), 1);
+        assert!(edit[0].as_check_menuitem_unchecked().is_checked());
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn reconcile_icons_and_default_labels() {
+        let menu = Menu::new();
+        let icon = IconMenuItem::with_id("open", "Open", true, None, None);
+        let copy = PredefinedMenuItem::copy(None);
+        let paste = PredefinedMenuItem::paste(Some("Paste!"));
+        menu.append_items(&[&icon, &copy, &paste]).unwrap();
+
+        let spec = MenuSpec::new(vec![
+            MenuItemSpec::icon("open", "Open...", true, None, None),
+            MenuItemSpec::predefined(PredefinedMenuItemType::Copy, None),
+            MenuItemSpec::predefined(PredefinedMenuItemType::Paste, None),
+        ]);
+        menu.reconcile(&spec).unwrap();
+        assert_eq!(menu.items()[0].id(), icon.id());
+        assert_eq!(icon.text(), "Open...");
+        assert_eq!(paste.text(), PredefinedMenuItemType::Paste.text());
+
+        PredefinedMenuItem::set_label_provider(BundledLabels::new("de").unwrap());
+        menu.reconcile(&spec).unwrap();
+        assert_eq!(copy.text(), "&Kopieren");
+        assert_eq!(paste.text(), "&Einfügen");
+        PredefinedMenuItem::remove_label_provider();
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn reconcile_rebuilds_about_items_with_new_metadata() {
+        let about = |version: &str| {
+            MenuSpec::new(vec![MenuItemSpec::predefined(
+                PredefinedMenuItemType::About(Some(AboutMetadata {
+                    version: Some(version.into()),
+                    ..Default::default()
+                })),
+                None,
+            )])
+        };
+        let menu = about("1.0").build().unwrap();
+        let id = menu.items()[0].id().clone();
+
+        menu.reconcile(&about("1.0")).unwrap();
+        assert_eq!(menu.items()[0].id(), &id);
+
+        menu.reconcile(&about("2.0")).unwrap();
+        assert_ne!(menu.items()[0].id(), &id);
+        match menu.items()[0]
+            .as_predefined_menuitem_unchecked()
+            .predefined_item_type()
+        {
+            PredefinedMenuItemType::About(Some(metadata)) => {
+                assert_eq!(metadata.version.as_deref(), Some("2.0"))
+            }
+            _ => panic!("expected an about item"),
+        }
+    }
+}
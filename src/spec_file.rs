@@ -0,0 +1,447 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use serde::Deserialize;
+
+use crate::{items::PredefinedMenuItemType, Menu, MenuItemSpec, MenuSpec};
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpecFile {
+    #[serde(default)]
+    items: Vec<ItemFile>,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+enum ItemFile {
+    Item {
+        id: String,
+        text: String,
+        #[serde(default = "enabled")]
+        enabled: bool,
+        accelerator: Option<String>,
+        text_key: Option<String>,
+    },
+    Check {
+        id: String,
+        text: String,
+        #[serde(default = "enabled")]
+        enabled: bool,
+        #[serde(default)]
+        checked: bool,
+        accelerator: Option<String>,
+        text_key: Option<String>,
+    },
+    Submenu {
+        id: String,
+        text: String,
+        #[serde(default = "enabled")]
+        enabled: bool,
+        #[serde(default)]
+        items: Vec<ItemFile>,
+        text_key: Option<String>,
+    },
+    Predefined {
+        item: String,
+        text: Option<String>,
+        text_key: Option<String>,
+    },
+    Separator,
+}
+
+impl ItemFile {
+    fn into_spec(self) -> crate::Result<MenuItemSpec> {
+        let (spec, text_key) = match self {
+            ItemFile::Item {
+                id,
+                text,
+                enabled,
+                accelerator,
+                text_key,
+            } => (
+                MenuItemSpec::menu_item(id, text, enabled, parse_accelerator(accelerator)?),
+                text_key,
+            ),
+            ItemFile::Check {
+                id,
+                text,
+                enabled,
+                checked,
+                accelerator,
+                text_key,
+            } => (
+                MenuItemSpec::check(id, text, enabled, checked, parse_accelerator(accelerator)?),
+                text_key,
+            ),
+            ItemFile::Submenu {
+                id,
+                text,
+                enabled,
+                items,
+                text_key,
+            } => (
+                MenuItemSpec::submenu(id, text, enabled, into_specs(items)?),
+                text_key,
+            ),
+            ItemFile::Predefined {
+                item,
+                text,
+                text_key,
+            } => {
+                let item_type = predefined_type(&item).ok_or_else(|| {
+                    crate::Error::InvalidSpec(format!("unknown predefined menu item `{item}`"))
+                })?;
+                (
+                    MenuItemSpec::predefined(item_type, text.as_deref()),
+                    text_key,
+                )
+            }
+            ItemFile::Separator => (MenuItemSpec::separator(), None),
+        };
+
+        Ok(match text_key {
+            Some(key) => spec.text_key(key),
+            None => spec,
+        })
+    }
+}
+
+fn into_specs(items: Vec<ItemFile>) -> crate::Result<Vec<MenuItemSpec>> {
+    items.into_iter().map(ItemFile::into_spec).collect()
+}
+
+fn parse_accelerator(
+    accelerator: Option<String>,
+) -> crate::Result<Option<crate::accelerator::Accelerator>> {
+    Ok(accelerator.map(|a| a.parse()).transpose()?)
+}
+
+fn predefined_type(name: &str) -> Option<PredefinedMenuItemType> {
+    use PredefinedMenuItemType as T;
+
+    Some(match name {
+        "separator" => T::Separator,
+        "copy" => T::Copy,
+        "cut" => T::Cut,
+        "paste" => T::Paste,
+        "select-all" => T::SelectAll,
+        "undo" => T::Undo,
+        "redo" => T::Redo,
+        "minimize" => T::Minimize,
+        "maximize" => T::Maximize,
+        "fullscreen" => T::Fullscreen,
+        "hide" => T::Hide,
+        "hide-others" => T::HideOthers,
+        "show-all" => T::ShowAll,
+        "close-window" => T::CloseWindow,
+        "quit" => T::Quit,
+        "about" => T::About(None),
+        "services" => T::Services,
+        "bring-all-to-front" => T::BringAllToFront,
+        "delete" => T::Delete,
+        "find" => T::Find,
+        "find-next" => T::FindNext,
+        "preferences" => T::Preferences,
+        "help" => T::Help,
+        "new-window" => T::NewWindow,
+        "zoom-in" => T::ZoomIn,
+        "zoom-out" => T::ZoomOut,
+        "zoom-reset" => T::ZoomReset,
+        _ => return None,
+    })
+}
+
+impl MenuSpec {
+    /// Parses a menu description from JSON.
+    ///
+    /// The description has a list of `items`, each with a `type`,
+    /// and the fields of the matching [`MenuItemSpec`] constructor:
+    ///
+    /// ```json
+    /// {
+    ///   "items": [
+    ///     {
+    ///       "type": "submenu", "id": "file", "text": "&File",
+    ///       "items": [
+    ///         { "type": "item", "id": "open", "text": "&Open...", "accelerator": "CmdOrCtrl+O" },
+    ///         { "type": "check", "id": "autosave", "text": "&Auto Save", "checked": true },
+    ///         { "type": "separator" },
+    ///         { "type": "predefined", "item": "quit" }
+    ///       ]
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// - `item`, `check` and `submenu` have an `id` and a `text`, and optionally `enabled`,
+    ///   which defaults to `true`, and a `text_key`, see [`MenuItemSpec::text_key`].
+    /// - `item` and `check` have an optional `accelerator`, see [`Accelerator`](crate::accelerator::Accelerator),
+    ///   and `check` an optional `checked`.
+    /// - `submenu` has its own `items`.
+    /// - `predefined` has the `item` type in kebab-case, like `select-all`,
+    ///   and optionally a `text` and a `text_key`.
+    ///
+    /// Requires the `spec-file` feature.
+    pub fn from_json(json: &str) -> crate::Result<MenuSpec> {
+        let file: SpecFile =
+            serde_json::from_str(json).map_err(|e| crate::Error::InvalidSpec(e.to_string()))?;
+        Ok(MenuSpec::new(into_specs(file.items)?))
+    }
+
+    /// Parses a menu description from TOML, with the same structure as [`MenuSpec::from_json`].
+    ///
+    /// ```toml
+    /// [[items]]
+    /// type = "submenu"
+    /// id = "file"
+    /// text = "&File"
+    ///
+    /// [[items.items]]
+    /// type = "item"
+    /// id = "open"
+    /// text = "&Open..."
+    /// accelerator = "CmdOrCtrl+O"
+    /// ```
+    pub fn from_toml(toml: &str) -> crate::Result<MenuSpec> {
+        let file: SpecFile =
+            toml::from_str(toml).map_err(|e| crate::Error::InvalidSpec(e.to_string()))?;
+        Ok(MenuSpec::new(into_specs(file.items)?))
+    }
+
+    /// Reads a menu description from a `.json` or `.toml` file,
+    /// see [`MenuSpec::from_json`] and [`MenuSpec::from_toml`].
+    pub fn from_file<P: AsRef<Path>>(path: P) -> crate::Result<MenuSpec> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| crate::Error::InvalidSpec(format!("{}: {e}", path.display())))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => MenuSpec::from_json(&contents),
+            Some("toml") => MenuSpec::from_toml(&contents),
+            _ => Err(crate::Error::InvalidSpec(format!(
+                "{}: expected a `.json` or `.toml` file",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Keeps a [`Menu`] in sync with a menu description file, see [`Menu::watch_spec`].
+///
+/// Changes are only picked up when [`SpecWatcher::poll`] is called.
+pub struct SpecWatcher {
+    menu: Menu,
+    path: PathBuf,
+    /// The modification time and length of the file when it was last read.
+    stamp: Cell<Option<(SystemTime, u64)>>,
+    on_error: RefCell<Box<dyn FnMut(crate::Error)>>,
+}
+
+impl SpecWatcher {
+    pub(crate) fn new<F: FnMut(crate::Error) + 'static>(
+        menu: Menu,
+        path: PathBuf,
+        on_error: F,
+    ) -> Self {
+        let watcher = Self {
+            stamp: Cell::new(stamp(&path)),
+            menu,
+            path,
+            on_error: RefCell::new(Box::new(on_error)),
+        };
+        watcher.reload();
+        watcher
+    }
+
+    /// Returns the path of the watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Re-reads the file if it changed since it was last read and applies it to the menu,
+    /// returns whether it was read.
+    ///
+    /// Nothing is reloaded until this is called, call it periodically from the event loop,
+    /// like from a timer every 500 milliseconds.
+    pub fn poll(&self) -> bool {
+        let stamp = stamp(&self.path);
+        if stamp == self.stamp.get() {
+            return false;
+        }
+        self.stamp.set(stamp);
+        self.reload();
+        true
+    }
+
+    /// Re-reads the file and applies it to the menu, even if it didn't change.
+    pub fn reload(&self) {
+        if let Err(e) = MenuSpec::from_file(&self.path).and_then(|spec| self.menu.reconcile(&spec))
+        {
+            (self.on_error.borrow_mut())(e);
+        }
+    }
+}
+
+impl fmt::Debug for SpecWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpecWatcher")
+            .field("menu", &self.menu.id())
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Menu, MenuSpec};
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn parse_spec_files() {
+        let json = MenuSpec::from_json(
+            r#"{ "items": [
+                { "type": "submenu", "id": "file", "text": "&File", "text_key": "file", "items": [
+                    { "type": "item", "id": "open", "text": "&Open", "accelerator": "CmdOrCtrl+O" },
+                    { "type": "check", "id": "wrap", "text": "&Wrap", "checked": true },
+                    { "type": "separator" },
+                    { "type": "predefined", "item": "select-all" }
+                ] }
+            ] }"#,
+        )
+        .unwrap();
+
+        let toml = MenuSpec::from_toml(
+            r#"
+            [[items]]
+            type = "submenu"
+            id = "file"
+            text = "&File"
+            text_key = "file"
+
+            [[items.items]]
+            type = "item"
+            id = "open"
+            text = "&Open"
+            accelerator = "CmdOrCtrl+O"
+
+            [[items.items]]
+            type = "check"
+            id = "wrap"
+            text = "&Wrap"
+            checked = true
+
+            [[items.items]]
+            type = "separator"
+
+            [[items.items]]
+            type = "predefined"
+            item = "select-all"
+            "#,
+        )
+        .unwrap();
+
+        let menu = json.build().unwrap();
+        let file = menu.items()[0].as_submenu_unchecked().clone();
+        assert_eq!(file.text_key().as_deref(), Some("file"));
+        assert_eq!(file.items().len(), 4);
+        assert!(file.items()[1].as_check_menuitem_unchecked().is_checked());
+        assert_eq!(format!("{json:?}"), format!("{toml:?}"));
+
+        for invalid in [
+            r#"{ "items": [{ "type": "predefined", "item": "nope" }] }"#,
+            r#"{ "items": [{ "type": "item", "id": "a", "text": "A", "accelerator": "Ctrl+" }] }"#,
+            r#"{ "items": [{ "type": "item", "id": "a" }] }"#,
+            r#"{ "items": [{ "type": "item", "id": "a", "text": "A", "typo": 1 }] }"#,
+        ] {
+            assert!(MenuSpec::from_json(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn watch_spec_file() {
+        use std::{cell::RefCell, rc::Rc};
+
+        let path = std::env::temp_dir().join(format!("muda-watch-{}.json", std::process::id()));
+        let write =
+            |items: &str| std::fs::write(&path, format!(r#"{{ "items": [{items}] }}"#)).unwrap();
+        write(r#"{ "type": "item", "id": "open", "text": "&Open" }"#);
+
+        let menu = Menu::new();
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let watcher = menu.watch_spec(&path, {
+            let errors = errors.clone();
+            move |e| errors.borrow_mut().push(e.to_string())
+        });
+        let open = menu.items()[0].as_menuitem_unchecked().clone();
+        assert_eq!(open.text(), "&Open");
+        assert!(!watcher.poll());
+
+        write(r#"{ "type": "item", "id": "open", "text": "&Open..." }, { "type": "separator" }"#);
+        assert!(watcher.poll());
+        assert_eq!(menu.items().len(), 2);
+        assert_eq!(open.text(), "&Open...");
+        assert_eq!(menu.items()[0].id(), open.id());
+
+        write(r#"{ "type": "item", "id": "open" }"#);
+        assert!(watcher.poll());
+        assert_eq!(menu.items().len(), 2);
+        assert_eq!(errors.borrow().len(), 1);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(watcher.poll());
+        assert_eq!(errors.borrow().len(), 2);
+        assert_eq!(open.text(), "&Open...");
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn reload_keeps_localized_text() {
+        let path =
+            std::env::temp_dir().join(format!("muda-relocalize-{}.json", std::process::id()));
+        let write =
+            |items: &str| std::fs::write(&path, format!(r#"{{ "items": [{items}] }}"#)).unwrap();
+        write(
+            r#"{ "type": "item", "id": "open", "text": "&Open", "text_key": "open" },
+            { "type": "item", "id": "save", "text": "&Save", "text_key": "save" },
+            { "type": "predefined", "item": "copy", "text_key": "copy" }"#,
+        );
+
+        let menu = Menu::new();
+        let watcher = menu.watch_spec(&path, |e| panic!("{e}"));
+        menu.relocalize(|key| format!("[{key}]"));
+
+        write(
+            r#"{ "type": "item", "id": "open", "text": "&Open...", "text_key": "open" },
+            { "type": "item", "id": "save", "text": "Save &As", "text_key": "save-as" },
+            { "type": "predefined", "item": "copy", "text_key": "copy" }"#,
+        );
+        watcher.reload();
+
+        let items = menu.items();
+        assert_eq!(items[0].as_menuitem_unchecked().text(), "[open]");
+        let save = items[1].as_menuitem_unchecked();
+        assert_eq!(save.text(), "Save &As");
+        assert_eq!(save.text_key().as_deref(), Some("save-as"));
+        assert_eq!(items[2].as_predefined_menuitem_unchecked().text(), "[copy]");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
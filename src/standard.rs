@@ -0,0 +1,314 @@
+// Copyright 2022-2022 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+use crate::{
+    group::separate_groups, util::Container, AboutMetadata, IsMenuItem, Menu, MenuItemKind,
+    PredefinedMenuItem, Submenu,
+};
+
+/// Options for [`Menu::standard`].
+#[derive(Clone, Default)]
+pub struct StandardMenuOptions {
+    app_name: Option<String>,
+    about_metadata: Option<AboutMetadata>,
+    preferences: bool,
+    help: bool,
+    items: Vec<(String, i32, MenuItemKind)>,
+}
+
+impl fmt::Debug for StandardMenuOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items = self
+            .items
+            .iter()
+            .map(|(group, priority, item)| (group, priority, item.id()))
+            .collect::<Vec<_>>();
+        f.debug_struct("StandardMenuOptions")
+            .field("app_name", &self.app_name)
+            .field("about_metadata", &self.about_metadata)
+            .field("preferences", &self.preferences)
+            .field("help", &self.help)
+            .field("items", &items)
+            .finish()
+    }
+}
+
+impl StandardMenuOptions {
+    /// Creates options for a standard menubar without the optional items.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the name of the app, used in the text of the About item and,
+    /// on macOS, of the app menu, Hide and Quit items.
+    ///
+    /// The text of these items still follows the label provider,
+    /// see [`PredefinedMenuItem::set_label_provider`].
+    pub fn app_name<S: Into<String>>(mut self, app_name: S) -> Self {
+        self.app_name.replace(app_name.into());
+        self
+    }
+
+    /// Set the metadata shown by the About item.
+    pub fn about_metadata(mut self, metadata: AboutMetadata) -> Self {
+        self.about_metadata.replace(metadata);
+        self
+    }
+
+    /// Add a Preferences item, to the app menu on macOS and to the Edit menu otherwise.
+    pub fn preferences(mut self, preferences: bool) -> Self {
+        self.preferences = preferences;
+        self
+    }
+
+    /// Add a Help item to the Help menu.
+    pub fn help(mut self, help: bool) -> Self {
+        self.help = help;
+        self
+    }
+
+    /// Add `item` to `group`, ordered by `priority`, see [`Menu::insert_in_group`].
+    ///
+    /// `group` is either one of the groups of the standard submenus, see [`Menu::standard`],
+    /// or the id of a standard submenu to add `item` next to it in the menubar,
+    /// with a negative `priority` to add it before and a positive one to add it after.
+    pub fn item(mut self, group: &str, priority: i32, item: &dyn IsMenuItem) -> Self {
+        self.items.push((group.to_string(), priority, item.kind()));
+        self
+    }
+}
+
+type Groups = Vec<(&'static str, Vec<PredefinedMenuItem>)>;
+
+pub(crate) fn standard(options: StandardMenuOptions) -> crate::Result<Menu> {
+    let app_name = options.app_name.as_deref();
+    let with_name = |item: PredefinedMenuItem| {
+        if let Some(app_name) = app_name {
+            item.set_default_app_name(app_name);
+        }
+        item
+    };
+
+    let about = with_name(PredefinedMenuItem::about(
+        None,
+        options.about_metadata.clone(),
+    ));
+    let preferences = options
+        .preferences
+        .then(|| PredefinedMenuItem::preferences(None));
+    let help = options.help.then(|| PredefinedMenuItem::help(None));
+
+    let submenus: Vec<(&str, &str, Groups)> = vec![
+        #[cfg(target_os = "macos")]
+        (
+            "app",
+            app_name.unwrap_or_default(),
+            vec![
+                ("app.about", vec![about]),
+                ("app.preferences", preferences.into_iter().collect()),
+                ("app.services", vec![PredefinedMenuItem::services(None)]),
+                (
+                    "app.hide",
+                    vec![
+                        with_name(PredefinedMenuItem::hide(None)),
+                        PredefinedMenuItem::hide_others(None),
+                        PredefinedMenuItem::show_all(None),
+                    ],
+                ),
+                ("app.quit", vec![with_name(PredefinedMenuItem::quit(None))]),
+            ],
+        ),
+        (
+            "file",
+            "&File",
+            vec![
+                ("file.new", vec![]),
+                (
+                    "file.close",
+                    vec![
+                        PredefinedMenuItem::close_window(None),
+                        #[cfg(not(target_os = "macos"))]
+                        PredefinedMenuItem::quit(None),
+                    ],
+                ),
+            ],
+        ),
+        (
+            "edit",
+            "&Edit",
+            vec![
+                (
+                    "edit.undo",
+                    vec![
+                        PredefinedMenuItem::undo(None),
+                        PredefinedMenuItem::redo(None),
+                    ],
+                ),
+                (
+                    "edit.clipboard",
+                    vec![
+                        PredefinedMenuItem::cut(None),
+                        PredefinedMenuItem::copy(None),
+                        PredefinedMenuItem::paste(None),
+                        PredefinedMenuItem::delete(None),
+                        PredefinedMenuItem::select_all(None),
+                    ],
+                ),
+                ("edit.find", vec![]),
+                #[cfg(not(target_os = "macos"))]
+                ("edit.preferences", preferences.into_iter().collect()),
+            ],
+        ),
+        (
+            "view",
+            "&View",
+            vec![
+                ("view.zoom", vec![]),
+                (
+                    "view.fullscreen",
+                    vec![PredefinedMenuItem::fullscreen(None)],
+                ),
+            ],
+        ),
+        (
+            "window",
+            "&Window",
+            vec![
+                (
+                    "window.main",
+                    vec![
+                        PredefinedMenuItem::minimize(None),
+                        PredefinedMenuItem::maximize(None),
+                    ],
+                ),
+                (
+                    "window.arrange",
+                    vec![PredefinedMenuItem::bring_all_to_front(None)],
+                ),
+            ],
+        ),
+        (
+            "help",
+            "&Help",
+            vec![
+                ("help.main", help.into_iter().collect()),
+                #[cfg(not(target_os = "macos"))]
+                ("help.about", vec![about]),
+            ],
+        ),
+    ];
+
+    let menu = Menu::new();
+    menu.batch(|menu| {
+        let submenus = submenus
+            .into_iter()
+            .map(|(id, text, groups)| {
+                let submenu = Submenu::with_id(id, text, true);
+                menu.insert_in_group(&submenu, id, 0)?;
+                for (group, items) in groups {
+                    submenu.add_group(group);
+                    for item in items.iter().filter(|i| i.is_supported()) {
+                        submenu.insert_in_group(item, group, 0)?;
+                    }
+                }
+                Ok(submenu)
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        for (group, priority, item) in &options.items {
+            let container = if menu.with_groups(|g| g.is_declared(group)) {
+                Container::Menu(menu)
+            } else {
+                match submenus
+                    .iter()
+                    .find(|s| s.with_groups(|g| g.is_declared(group)))
+                {
+                    Some(submenu) => Container::Submenu(submenu),
+                    None => return Err(crate::Error::UnknownGroup(group.clone())),
+                }
+            };
+            crate::group::insert_in_group(container, item.as_ref(), group, *priority)?;
+        }
+
+        for submenu in &submenus {
+            if submenu.items().is_empty() {
+                menu.remove(submenu)?;
+                continue;
+            }
+
+            separate_groups(Container::Submenu(submenu))?;
+
+            #[cfg(target_os = "macos")]
+            match submenu.id().0.as_str() {
+                "window" => submenu.set_as_windows_menu_for_nsapp(),
+                "help" => submenu.set_as_help_menu_for_nsapp(),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    })?;
+
+    Ok(menu)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Menu, MenuItem, PredefinedMenuItemType, StandardMenuOptions};
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn standard_menu() {
+        let find = MenuItem::with_id("find", "&Find", true, None);
+        let tools = MenuItem::with_id("tools", "&Tools", true, None);
+        let menu = Menu::standard(
+            StandardMenuOptions::new()
+                .app_name("App")
+                .item("edit.find", 0, &find)
+                .item("view", 1, &tools),
+        )
+        .unwrap();
+
+        let warnings = menu.lint();
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        let edit = menu.items()[menu.index_of(&"edit".into()).unwrap()].clone();
+        let edit = edit.as_submenu_unchecked();
+        let last = edit.items().len() - 1;
+        assert_eq!(edit.items()[last].id(), "find");
+        assert!(edit.items()[last - 1]
+            .as_predefined_menuitem()
+            .map(|i| matches!(i.predefined_item_type(), PredefinedMenuItemType::Separator))
+            .unwrap_or(false));
+
+        #[cfg(target_os = "linux")]
+        assert_eq!(
+            menu.items()
+                .iter()
+                .map(|i| i.id().0.clone())
+                .collect::<Vec<_>>(),
+            ["file", "edit", "view", "tools", "window", "help"]
+        );
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let help = menu.items()[menu.index_of(&"help".into()).unwrap()].clone();
+            let about = help.as_submenu_unchecked().items().last().unwrap().clone();
+            let about = about.as_predefined_menuitem().unwrap();
+            assert_eq!(about.text(), "&About App");
+
+            crate::PredefinedMenuItem::set_label_provider(crate::BundledLabels::new("de").unwrap());
+            assert_eq!(about.text(), "Ü&ber App");
+            crate::PredefinedMenuItem::remove_label_provider();
+            assert_eq!(about.text(), "&About App");
+        }
+
+        assert!(matches!(
+            Menu::standard(StandardMenuOptions::new().item("missing", 0, &find)),
+            Err(crate::Error::UnknownGroup(_))
+        ));
+    }
+}
@@ -2,7 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use crate::{group::MenuGroups, IsMenuItem, Menu, MenuItemKind, Submenu};
 
 #[derive(Clone, Copy, Debug)]
 pub enum AddOp {
@@ -27,3 +33,295 @@ impl Counter {
         self.0.fetch_add(1, Ordering::Relaxed)
     }
 }
+
+type TaskKey = (usize, &'static str);
+
+#[derive(Default)]
+struct Batch {
+    depth: usize,
+    tasks: Vec<Option<Box<dyn FnOnce()>>>,
+    keys: HashMap<TaskKey, usize>,
+}
+
+thread_local! {
+    static BATCH: RefCell<Option<Batch>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` while deferring native updates registered through [`defer`]
+/// until the outermost call returns.
+///
+/// If `f` panics, the deferred updates are thrown away instead of being applied while unwinding.
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    /// Ends the batch when `f` unwinds, it is forgotten when `f` returns normally.
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            drop(end_batch());
+        }
+    }
+
+    BATCH.with(|batch| {
+        batch
+            .borrow_mut()
+            .get_or_insert_with(Default::default)
+            .depth += 1;
+    });
+    let guard = Guard;
+
+    let ret = f();
+
+    std::mem::forget(guard);
+    for task in end_batch() {
+        task();
+    }
+
+    ret
+}
+
+/// Leaves the current [`batch`] and returns its deferred tasks if it was the outermost one.
+fn end_batch() -> Vec<Box<dyn FnOnce()>> {
+    BATCH.with(|batch| {
+        let mut batch = batch.borrow_mut();
+        let depth = &mut batch.as_mut().unwrap().depth;
+        *depth -= 1;
+        if *depth == 0 {
+            batch
+                .take()
+                .map(|b| b.tasks.into_iter().flatten().collect())
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        }
+    })
+}
+
+/// Returns `true` if called inside [`batch`].
+#[allow(unused)]
+pub fn is_batching() -> bool {
+    BATCH.with(|batch| batch.borrow().is_some())
+}
+
+/// Runs `task` right away, or if called inside [`batch`], defers it until the batch ends.
+///
+/// `key` identifies the object and the update, a deferred task replaces
+/// any pending task with the same key so only the last update is applied.
+#[allow(unused)]
+pub fn defer<F: FnOnce() + 'static>(key: TaskKey, task: F) {
+    let task = BATCH.with(|batch| match batch.borrow_mut().as_mut() {
+        Some(batch) => {
+            if let Some(i) = batch.keys.insert(key, batch.tasks.len()) {
+                batch.tasks[i] = None;
+            }
+            batch.tasks.push(Some(Box::new(task)));
+            None
+        }
+        None => Some(task),
+    });
+
+    if let Some(task) = task {
+        task();
+    }
+}
+
+/// Moves the element at `from` to `to`, shifting the elements in between.
+///
+/// Returns [`Error::NotAChildOfThisMenu`](crate::Error::NotAChildOfThisMenu) if any of them is out of bounds.
+pub fn move_element<T>(items: &mut Vec<T>, from: usize, to: usize) -> crate::Result<()> {
+    if from >= items.len() || to >= items.len() {
+        return Err(crate::Error::NotAChildOfThisMenu);
+    }
+    let item = items.remove(from);
+    items.insert(to, item);
+    Ok(())
+}
+
+/// Makes `container` hold `items` in order, see [`Menu::replace_items`].
+///
+/// The items it already holds are only moved, the others are removed and the new ones inserted.
+pub fn replace_items(container: Container, items: &[&dyn IsMenuItem]) -> crate::Result<()> {
+    batch(|| {
+        let mut current = Vec::new();
+        for item in container.items() {
+            if items.iter().any(|i| i.is_same_item(item.as_ref())) {
+                current.push(item);
+            } else {
+                container.remove(item.as_ref())?;
+            }
+        }
+
+        for (position, item) in items.iter().enumerate() {
+            let found = current
+                .iter()
+                .skip(position)
+                .position(|i| i.as_ref().is_same_item(*item))
+                .map(|i| i + position);
+
+            match found {
+                Some(i) => {
+                    if i != position {
+                        container.move_item(i, position)?;
+                        let moved = current.remove(i);
+                        current.insert(position, moved);
+                    }
+                }
+                None => {
+                    container.insert(*item, position)?;
+                    current.insert(position, item.kind());
+                }
+            }
+        }
+
+        // anything left was held more than once
+        for item in current.drain(items.len()..) {
+            container.remove(item.as_ref())?;
+        }
+
+        Ok(())
+    })
+}
+
+/// A [`Menu`] or a [`Submenu`], anything that can hold menu items.
+#[derive(Clone, Copy)]
+pub enum Container<'a> {
+    Menu(&'a Menu),
+    Submenu(&'a Submenu),
+}
+
+impl Container<'_> {
+    pub fn items(&self) -> Vec<MenuItemKind> {
+        match self {
+            Container::Menu(m) => m.items(),
+            Container::Submenu(m) => m.items(),
+        }
+    }
+
+    pub fn insert(&self, item: &dyn IsMenuItem, position: usize) -> crate::Result<()> {
+        match self {
+            Container::Menu(m) => m.insert(item, position),
+            Container::Submenu(m) => m.insert(item, position),
+        }
+    }
+
+    pub fn remove(&self, item: &dyn IsMenuItem) -> crate::Result<()> {
+        match self {
+            Container::Menu(m) => m.remove(item),
+            Container::Submenu(m) => m.remove(item),
+        }
+    }
+
+    pub fn move_item(&self, from: usize, to: usize) -> crate::Result<()> {
+        match self {
+            Container::Menu(m) => m.move_item(from, to),
+            Container::Submenu(m) => m.move_item(from, to),
+        }
+    }
+
+    pub fn with_groups<R>(&self, f: impl FnOnce(&mut MenuGroups) -> R) -> R {
+        match self {
+            Container::Menu(m) => m.with_groups(f),
+            Container::Submenu(m) => m.with_groups(f),
+        }
+    }
+}
+
+/// Sets the text of every item in `items` and their submenus that has a translation key
+/// to `localize(key)`, items for which it returns `None` are left untouched.
+pub fn relocalize(items: &[MenuItemKind], localize: &dyn Fn(&str) -> Option<String>) {
+    for item in items {
+        let key = item.child().text_key();
+        if let Some(text) = key.and_then(|key| localize(&key)) {
+            match item {
+                MenuItemKind::MenuItem(i) => i.set_text(text),
+                MenuItemKind::Submenu(i) => i.set_text(text),
+                MenuItemKind::Predefined(i) => i.set_text(text),
+                MenuItemKind::Check(i) => i.set_text(text),
+                MenuItemKind::Icon(i) => i.set_text(text),
+            }
+        }
+
+        if let MenuItemKind::Submenu(submenu) = item {
+            relocalize(&submenu.items(), localize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::{batch, defer};
+    use crate::{Menu, MenuItemBuilder, PredefinedMenuItem, SubmenuBuilder};
+
+    #[test]
+    fn batch_defers_and_coalesces() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let push = |key: usize, value: &'static str| {
+            let log = log.clone();
+            defer((key, "test"), move || log.borrow_mut().push(value));
+        };
+
+        batch(|| {
+            push(1, "a");
+            batch(|| push(2, "b"));
+            push(1, "c");
+            assert!(log.borrow().is_empty());
+        });
+        assert_eq!(*log.borrow(), ["b", "c"]);
+
+        push(3, "d");
+        assert_eq!(*log.borrow(), ["b", "c", "d"]);
+    }
+
+    #[test]
+    fn batch_discards_tasks_on_panic() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let push = |value: &'static str| {
+            let log = log.clone();
+            defer((1, "test"), move || log.borrow_mut().push(value));
+        };
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            batch(|| {
+                push("a");
+                batch(|| panic!("inside a batch"));
+            })
+        }));
+        assert!(result.is_err());
+        assert!(log.borrow().is_empty());
+        assert!(!super::is_batching());
+
+        batch(|| push("b"));
+        assert_eq!(*log.borrow(), ["b"]);
+    }
+
+    #[test]
+    #[cfg_attr(all(miri, not(target_os = "linux")), ignore)]
+    fn relocalize_items() {
+        let save = MenuItemBuilder::new()
+            .text("&Save")
+            .text_key("file.save")
+            .build();
+        let plain = MenuItemBuilder::new().text("Plain").build();
+        let copy = PredefinedMenuItem::copy(None);
+        copy.set_text_key(Some("edit.copy"));
+        let file = SubmenuBuilder::new()
+            .text("&File")
+            .text_key("file")
+            .items(&[&save, &plain, &copy])
+            .build()
+            .unwrap();
+        let menu = Menu::with_items(&[&file]).unwrap();
+
+        menu.relocalize(|key| format!("[{key}]"));
+        assert_eq!(file.text(), "[file]");
+        assert_eq!(save.text(), "[file.save]");
+        assert_eq!(copy.text(), "[edit.copy]");
+        assert_eq!(plain.text(), "Plain");
+        assert_eq!(save.text_key().as_deref(), Some("file.save"));
+
+        // explicitly localized items are no longer relabelled by label providers
+        PredefinedMenuItem::remove_label_provider();
+        assert_eq!(copy.text(), "[edit.copy]");
+    }
+}